@@ -19,6 +19,7 @@ pub mod projects;
 pub mod pulls;
 pub mod ratelimit;
 pub mod repos;
+pub mod rulesets;
 pub mod search;
 pub mod teams;
 pub mod users;
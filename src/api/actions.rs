@@ -106,6 +106,13 @@ impl<'octo> ListRepositoryArtifacts<'octo> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListRepositoryArtifacts<'_>,
+    WorkflowListArtifact,
+    |b| b.crab,
+    |b| Ok(b.send().await?.value.unwrap_or_default())
+}
+
 pub struct ListWorkflowRunArtifacts<'octo> {
     crab: &'octo Octocrab,
     owner: String,
@@ -181,6 +188,13 @@ impl<'octo> ListWorkflowRunArtifacts<'octo> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListWorkflowRunArtifacts<'_>,
+    WorkflowListArtifact,
+    |b| b.crab,
+    |b| Ok(b.send().await?.value.unwrap_or_default())
+}
+
 pub struct WorkflowDispatchBuilder<'octo> {
     crab: &'octo Octocrab,
     owner: String,
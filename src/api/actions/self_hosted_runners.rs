@@ -83,6 +83,12 @@ impl<'octo, 'r> ListSelfHostedRunnersBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListSelfHostedRunnersBuilder<'_, '_>,
+    crate::models::actions::SelfHostedRunner,
+    |b| b.handler.crab
+}
+
 /// A builder pattern struct for creating just-in-time runner configurations.
 ///
 /// Created by [`ActionsHandler::create_org_jit_runner_config`] or
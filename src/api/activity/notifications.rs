@@ -333,3 +333,5 @@ impl<'octo> ListNotificationsBuilder<'octo> {
         self.crab.get(&self.url, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! { ListNotificationsBuilder<'_>, Notification, |b| b.crab }
@@ -135,3 +135,5 @@ impl<'octo> ListReposStarredByUserBuilder<'octo> {
         self
     }
 }
+
+crate::page::impl_paginate! { ListReposStarredByUserBuilder<'_>, StarredRepository, |b| b.crab }
@@ -1,8 +1,15 @@
-use crate::{models::InstallationId, Octocrab};
+use crate::{
+    models::{apps::OAuthAuthorization, InstallationId},
+    Octocrab,
+};
 use http::request::Builder;
 use http::Method;
+use secrecy::{ExposeSecret, SecretString};
 
 mod installations;
+mod scoped_token;
+
+pub use scoped_token::CreateScopedTokenBuilder;
 
 /// A client to [GitHub's apps API][apps-api].
 ///
@@ -108,6 +115,145 @@ impl<'octo> AppsRequestHandler<'octo> {
         self.crab.get(&route, None::<&()>).await
     }
 
+    /// Completes the [manifest flow] by exchanging the `code` GitHub
+    /// redirected back with for the created app and its credentials. The
+    /// code expires after one hour, and does not require authentication.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let created = octocrab.apps().create_from_manifest("code").await?;
+    /// let app = octocrab::Octocrab::builder()
+    ///     .app(created.app.id, created.encoding_key()?)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [manifest flow]: https://docs.github.com/en/apps/sharing-github-apps/registering-a-github-app-from-a-manifest
+    pub async fn create_from_manifest(
+        &self,
+        code: impl AsRef<str>,
+    ) -> crate::Result<crate::models::apps::AppManifestConversion> {
+        let route = format!("/app-manifests/{code}/conversions", code = code.as_ref());
+
+        self.crab.post(&route, None::<&()>).await
+    }
+
+    /// Checks that `access_token` is valid for the OAuth app or GitHub App
+    /// `client_id`, returning its authorization.
+    ///
+    /// The `Octocrab` must authenticate with
+    /// [`basic_auth`](crate::OctocrabBuilder::basic_auth), using the client
+    /// ID and client secret of the app as username and password.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use secrecy::SecretString;
+    ///
+    /// let app = octocrab::Octocrab::builder()
+    ///     .basic_auth("Iv1.client_id".to_string(), "client_secret".to_string())
+    ///     .build()?;
+    /// let authorization = app
+    ///     .apps()
+    ///     .check_token("Iv1.client_id", &SecretString::from("gho_leaked"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_token(
+        &self,
+        client_id: impl AsRef<str>,
+        access_token: &SecretString,
+    ) -> crate::Result<OAuthAuthorization> {
+        let route = format!("/applications/{}/token", client_id.as_ref());
+
+        self.crab
+            .post(&route, Some(&AccessToken::from(access_token)))
+            .await
+    }
+
+    /// Resets `access_token`, invalidating it, and returns the authorization
+    /// with its new token. See [`check_token`](Self::check_token) for the
+    /// required authentication.
+    pub async fn reset_token(
+        &self,
+        client_id: impl AsRef<str>,
+        access_token: &SecretString,
+    ) -> crate::Result<OAuthAuthorization> {
+        let route = format!("/applications/{}/token", client_id.as_ref());
+
+        self.crab
+            .patch(&route, Some(&AccessToken::from(access_token)))
+            .await
+    }
+
+    /// Revokes `access_token`. See [`check_token`](Self::check_token) for the
+    /// required authentication.
+    pub async fn delete_token(
+        &self,
+        client_id: impl AsRef<str>,
+        access_token: &SecretString,
+    ) -> crate::Result<()> {
+        let route = format!("/applications/{}/token", client_id.as_ref());
+
+        crate::map_github_error(
+            self.crab
+                ._delete(route, Some(&AccessToken::from(access_token)))
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Revokes the authorization of the app by the owner of `access_token`,
+    /// deleting all its tokens. See [`check_token`](Self::check_token) for
+    /// the required authentication.
+    pub async fn delete_authorization(
+        &self,
+        client_id: impl AsRef<str>,
+        access_token: &SecretString,
+    ) -> crate::Result<()> {
+        let route = format!("/applications/{}/grant", client_id.as_ref());
+
+        crate::map_github_error(
+            self.crab
+                ._delete(route, Some(&AccessToken::from(access_token)))
+                .await?,
+        )
+        .await
+        .map(drop)
+    }
+
+    /// Creates a user access token of a GitHub App restricted to a user or
+    /// organization, repositories and permissions. See
+    /// [`check_token`](Self::check_token) for the required authentication.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let app = octocrab::Octocrab::default();
+    /// use octocrab::auth::InstallationTokenScope;
+    /// use secrecy::SecretString;
+    ///
+    /// let token = SecretString::from("ghu_token");
+    /// let authorization = app
+    ///     .apps()
+    ///     .create_scoped_token("Iv1.client_id", &token)
+    ///     .target("octocat")
+    ///     .scope(InstallationTokenScope::new().repository("hello-world"))
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_scoped_token<'b>(
+        &'b self,
+        client_id: impl Into<String>,
+        access_token: &'b SecretString,
+    ) -> CreateScopedTokenBuilder<'octo, 'b> {
+        CreateScopedTokenBuilder::new(self, client_id.into(), access_token.expose_secret())
+    }
+
     /// Get a GitHub App by its slug.
     pub async fn get_app(
         &self,
@@ -118,3 +264,16 @@ impl<'octo> AppsRequestHandler<'octo> {
         self.crab.get(&route, None::<&()>).await
     }
 }
+
+#[derive(serde::Serialize)]
+struct AccessToken<'a> {
+    access_token: &'a str,
+}
+
+impl<'a> From<&'a SecretString> for AccessToken<'a> {
+    fn from(access_token: &'a SecretString) -> Self {
+        Self {
+            access_token: access_token.expose_secret(),
+        }
+    }
+}
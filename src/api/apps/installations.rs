@@ -53,6 +53,12 @@ impl<'octo, 'b> InstallationsRequestBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! {
+    InstallationsRequestBuilder<'_, '_>,
+    crate::models::Installation,
+    |b| b.handler.crab
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,62 @@
+use super::*;
+use crate::auth::InstallationTokenScope;
+use crate::models::{apps::OAuthAuthorization, UserOrOrgId};
+
+/// A builder pattern struct for creating a scoped user access token.
+///
+/// created by [`AppsRequestHandler::create_scoped_token`]
+#[derive(serde::Serialize)]
+pub struct CreateScopedTokenBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b AppsRequestHandler<'octo>,
+    #[serde(skip)]
+    client_id: String,
+    access_token: &'b str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_id: Option<UserOrOrgId>,
+    #[serde(flatten)]
+    scope: InstallationTokenScope,
+}
+
+impl<'octo, 'b> CreateScopedTokenBuilder<'octo, 'b> {
+    pub(crate) fn new(
+        handler: &'b AppsRequestHandler<'octo>,
+        client_id: String,
+        access_token: &'b str,
+    ) -> Self {
+        Self {
+            handler,
+            client_id,
+            access_token,
+            target: None,
+            target_id: None,
+            scope: InstallationTokenScope::default(),
+        }
+    }
+
+    /// The login of the user or organization the token is scoped to.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// The ID of the user or organization the token is scoped to.
+    pub fn target_id(mut self, target_id: impl Into<UserOrOrgId>) -> Self {
+        self.target_id = Some(target_id.into());
+        self
+    }
+
+    /// The repositories and permissions the token is restricted to.
+    pub fn scope(mut self, scope: InstallationTokenScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<OAuthAuthorization> {
+        let route = format!("/applications/{}/token/scoped", self.client_id);
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
@@ -287,17 +287,26 @@ impl<'octo, 'r> ListCheckRunsInCheckSuiteBuilder<'octo, 'r> {
 
     /// Send the actual request.
     pub async fn send(self) -> Result<models::checks::ListCheckRuns> {
-        let route = format!(
+        self.handler.crab.get(self.route(), Some(&self)).await
+    }
+
+    fn route(&self) -> String {
+        format!(
             "/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs",
             owner = self.handler.owner,
             repo = self.handler.repo,
             check_suite_id = self.check_suite_id,
-        );
-
-        self.handler.crab.get(route, Some(&self)).await
+        )
     }
 }
 
+crate::page::impl_paginate! {
+    ListCheckRunsInCheckSuiteBuilder<'_, '_>,
+    models::checks::CheckRun,
+    |b| b.handler.crab,
+    |b| b.handler.crab.get(b.route(), Some(&b)).await
+}
+
 #[derive(serde::Serialize)]
 pub struct ListCheckRunsForGitRefBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -334,17 +343,26 @@ impl<'octo, 'r> ListCheckRunsForGitRefBuilder<'octo, 'r> {
 
     /// Send the actual request.
     pub async fn send(self) -> Result<models::checks::ListCheckRuns> {
-        let route = format!(
+        self.handler.crab.get(self.route(), Some(&self)).await
+    }
+
+    fn route(&self) -> String {
+        format!(
             "/repos/{owner}/{repo}/commits/{ref}/check-runs",
             owner = self.handler.owner,
             repo = self.handler.repo,
             ref = self.git_ref,
-        );
-
-        self.handler.crab.get(route, Some(&self)).await
+        )
     }
 }
 
+crate::page::impl_paginate! {
+    ListCheckRunsForGitRefBuilder<'_, '_>,
+    models::checks::CheckRun,
+    |b| b.handler.crab,
+    |b| b.handler.crab.get(b.route(), Some(&b)).await
+}
+
 #[derive(serde::Serialize)]
 pub struct ListCheckSuitesForGitRefBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -374,14 +392,16 @@ impl<'octo, 'r> crate::checks::ListCheckSuitesForGitRefBuilder<'octo, 'r> {
     /// Send the actual request to /repos/{owner}/{repo}/commits/{ref}/check-suites
     /// See <https://docs.github.com/en/rest/checks/suites?apiVersion=2022-11-28#list-check-suites-for-a-git-reference>
     pub async fn send(self) -> Result<models::checks::ListCheckSuites> {
-        let route = format!(
+        self.handler.crab.get(self.route(), Some(&self)).await
+    }
+
+    fn route(&self) -> String {
+        format!(
             "/repos/{owner}/{repo}/commits/{ref}/check-suites",
             owner = self.handler.owner,
             repo = self.handler.repo,
             ref = self.git_ref,
-        );
-
-        self.handler.crab.get(route, Some(&self)).await
+        )
     }
 
     /// Results per page (max 100).
@@ -409,6 +429,13 @@ impl<'octo, 'r> crate::checks::ListCheckSuitesForGitRefBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListCheckSuitesForGitRefBuilder<'_, '_>,
+    models::checks::CheckSuite,
+    |b| b.handler.crab,
+    |b| b.handler.crab.get(b.route(), Some(&b)).await
+}
+
 impl<'octo> ChecksHandler<'octo> {
     pub(crate) fn new(crab: &'octo Octocrab, owner: String, repo: String) -> Self {
         Self { crab, owner, repo }
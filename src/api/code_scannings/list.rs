@@ -98,3 +98,9 @@ impl<'octo, 'b> ListCodeScanningsBuilder<'octo, 'b> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListCodeScanningsBuilder<'_, '_>,
+    models::code_scannings::CodeScanningAlert,
+    |b| b.handler.crab
+}
@@ -39,13 +39,22 @@ impl<'octo, 'r> AssociatedCheckRunsBuilder<'octo, 'r> {
 
     /// Send the actual request.
     pub async fn send(self) -> Result<ListCheckRuns> {
-        let route = format!(
+        self.handler.crab.get(self.route(), Some(&self)).await
+    }
+
+    fn route(&self) -> String {
+        format!(
             "/repos/{owner}/{repo}/commits/{reference}/check-runs",
             owner = self.handler.owner,
             repo = self.handler.repo,
             reference = self.reference.full_ref_url()
-        );
-
-        self.handler.crab.get(route, Some(&self)).await
+        )
     }
 }
+
+crate::page::impl_paginate! {
+    AssociatedCheckRunsBuilder<'_, '_>,
+    crate::models::checks::CheckRun,
+    |b| b.handler.crab,
+    |b| b.handler.crab.get(b.route(), Some(&b)).await
+}
@@ -68,6 +68,12 @@ impl<'octo, 'r> AssociatedPullRequestsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    AssociatedPullRequestsBuilder<'_, '_>,
+    models::pulls::PullRequest,
+    |b| b.handler.crab
+}
+
 #[cfg(test)]
 mod tests {
 
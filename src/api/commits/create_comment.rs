@@ -12,6 +12,8 @@ pub struct CreateCommentBuilder<'octo, 'r> {
     position: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<u64>,
+    #[serde(skip)]
+    retryable: bool,
 }
 
 impl<'octo, 'r> CreateCommentBuilder<'octo, 'r> {
@@ -23,6 +25,7 @@ impl<'octo, 'r> CreateCommentBuilder<'octo, 'r> {
             path: None,
             position: None,
             line: None,
+            retryable: false,
         }
     }
 
@@ -35,7 +38,10 @@ impl<'octo, 'r> CreateCommentBuilder<'octo, 'r> {
             commit_sha = self.sha,
         );
 
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .send_mutation(http::Method::POST, route, Some(&self), self.retryable)
+            .await
     }
 
     /// Relative path of the file to comment on.
@@ -58,6 +64,14 @@ impl<'octo, 'r> CreateCommentBuilder<'octo, 'r> {
         self.line = line.into();
         self
     }
+
+    /// Allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`]. *NOTE: If GitHub processed the first attempt,
+    /// the comment is created twice.*
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -327,6 +327,8 @@ impl<'octo> ListStarredReposBuilder<'octo> {
     }
 }
 
+crate::page::impl_paginate! { ListStarredReposBuilder<'_>, Repository, |b| b.crab }
+
 /// A builder pattern struct for listing repositories for authenticated user.
 ///
 /// Created by [`CurrentAuthHandler::list_repos_for_authenticated_user`].
@@ -464,6 +466,8 @@ impl<'octo> ListReposForAuthenticatedUserBuilder<'octo> {
     }
 }
 
+crate::page::impl_paginate! { ListReposForAuthenticatedUserBuilder<'_>, Repository, |b| b.crab }
+
 /// A builder struct for initializing query parameters for use with the
 /// `/gists` endpoint.
 ///
@@ -527,6 +531,8 @@ impl<'octo> ListGistsForAuthenticatedUserBuilder<'octo> {
     }
 }
 
+crate::page::impl_paginate! { ListGistsForAuthenticatedUserBuilder<'_>, Gist, |b| b.crab }
+
 #[derive(serde::Serialize)]
 pub struct ListStarredGistsBuilder<'octo> {
     /// Client under use for building the request.
@@ -580,6 +586,8 @@ impl<'octo> ListStarredGistsBuilder<'octo> {
     }
 }
 
+crate::page::impl_paginate! { ListStarredGistsBuilder<'_>, Gist, |b| b.crab }
+
 /// A builder pattern struct for listing organizations the authenticated user is a member of.
 ///
 /// Created by [`CurrentAuthHandler::list_org_memberships_for_authenticated_user`].
@@ -630,6 +638,12 @@ impl<'octo> ListOrgMembershipsForAuthenticatedUserBuilder<'octo> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListOrgMembershipsForAuthenticatedUserBuilder<'_>,
+    MembershipInvitation,
+    |b| b.crab
+}
+
 /// A builder pattern struct for listing the installations accessible to a user access token.
 ///
 /// Created by [`CurrentAuthHandler::list_app_installations_accessible_to_user`].
@@ -677,3 +691,9 @@ impl<'octo> ListAppInstallationsAccessibleToUserBuilder<'octo> {
         self.crab.get("/user/installations", (&self).into()).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListAppInstallationsAccessibleToUserBuilder<'_>,
+    Installation,
+    |b| b.crab
+}
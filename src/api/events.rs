@@ -85,3 +85,10 @@ impl<'octo> EventsBuilder<'octo> {
         }
     }
 }
+
+crate::page::impl_paginate! {
+    EventsBuilder<'_>,
+    events::Event,
+    |b| b.crab,
+    |b| Ok(b.send().await?.value.unwrap_or_default())
+}
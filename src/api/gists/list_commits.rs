@@ -40,3 +40,9 @@ impl<'octo, 'b> ListCommitsBuilder<'octo, 'b> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListCommitsBuilder<'_, '_>,
+    crate::models::gists::GistCommit,
+    |b| b.handler.crab
+}
@@ -48,3 +48,5 @@ impl<'octo, 'b> ListGistForksBuilder<'octo, 'b> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! { ListGistForksBuilder<'_, '_>, Gist, |b| b.handler.crab }
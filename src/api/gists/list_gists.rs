@@ -73,6 +73,12 @@ impl<'octo, T: EndpointSelector> ListGistsBuilder<'octo, T> {
     }
 }
 
+crate::page::impl_paginate! {
+    [T: EndpointSelector + Send + Sync] ListGistsBuilder<'_, T>,
+    crate::models::gists::Gist,
+    |b| b.crab
+}
+
 /// Handles query data for the `GET /gists` endpoint.
 ///
 /// This endpoint has differing behaviour depending on the status of
@@ -139,3 +145,5 @@ impl<'octo> ListUserGistsBuilder<'octo> {
             .await
     }
 }
+
+crate::page::impl_paginate! { ListUserGistsBuilder<'_>, Gist, |b| b.crab }
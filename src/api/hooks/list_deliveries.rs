@@ -53,3 +53,9 @@ impl<'octo, 'r> ListHooksDeliveriesBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListHooksDeliveriesBuilder<'_, '_>,
+    crate::models::hooks::Delivery,
+    |b| b.handler.crab
+}
@@ -325,6 +325,8 @@ impl<'octo, 'r> ListAssigneesBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! { ListAssigneesBuilder<'_, '_>, models::Author, |b| b.handler.crab }
+
 /// # Labels
 impl IssueHandler<'_> {
     /// Adds `labels` to an issue.
@@ -547,11 +549,46 @@ impl IssueHandler<'_> {
         &self,
         number: u64,
         body: impl AsRef<str>,
+    ) -> Result<models::issues::Comment> {
+        self.send_create_comment(number, body.as_ref(), false).await
+    }
+
+    /// Creates a comment in the issue like [`IssueHandler::create_comment`],
+    /// but allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`]. *NOTE: If GitHub processed the first attempt, the
+    /// comment is created twice.*
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comment = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .create_comment_retryable(101, "Beep Boop")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_comment_retryable(
+        &self,
+        number: u64,
+        body: impl AsRef<str>,
+    ) -> Result<models::issues::Comment> {
+        self.send_create_comment(number, body.as_ref(), true).await
+    }
+
+    async fn send_create_comment(
+        &self,
+        number: u64,
+        body: &str,
+        retryable: bool,
     ) -> Result<models::issues::Comment> {
         let route = format!("/{}/issues/{issue}/comments", self.repo, issue = number);
 
         self.crab
-            .post(route, Some(&serde_json::json!({ "body": body.as_ref() })))
+            .send_mutation(
+                http::Method::POST,
+                route,
+                Some(&serde_json::json!({ "body": body })),
+                retryable,
+            )
             .await
     }
 
@@ -749,6 +786,12 @@ impl<'octo, 'r> ListCommentsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListCommentsBuilder<'_, '_>,
+    models::issues::Comment,
+    |b| b.handler.crab
+}
+
 #[derive(serde::Serialize)]
 pub struct ListIssueCommentsBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -815,6 +858,12 @@ impl<'octo, 'r> ListIssueCommentsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListIssueCommentsBuilder<'_, '_>,
+    models::issues::Comment,
+    |b| b.handler.crab
+}
+
 #[derive(serde::Serialize)]
 pub struct ListTimelineEventsBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -860,6 +909,12 @@ impl<'octo, 'r> ListTimelineEventsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListTimelineEventsBuilder<'_, '_>,
+    models::timelines::TimelineEvent,
+    |b| b.handler.crab
+}
+
 // Timeline
 impl IssueHandler<'_> {
     /// Lists events in the issue timeline.
@@ -964,6 +1019,12 @@ impl<'octo, 'r> ListReactionsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListReactionsBuilder<'_, '_>,
+    models::reactions::Reaction,
+    |b| b.handler.crab
+}
+
 #[derive(serde::Serialize)]
 pub struct ListCommentReactionsBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -1009,6 +1070,12 @@ impl<'octo, 'r> ListCommentReactionsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListCommentReactionsBuilder<'_, '_>,
+    models::reactions::Reaction,
+    |b| b.handler.crab
+}
+
 impl IssueHandler<'_> {
     /// Creates a reaction for an issue.
     /// ```no_run
@@ -13,6 +13,8 @@ pub struct CreateIssueBuilder<'octo, 'r> {
     labels: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     assignees: Option<Vec<String>>,
+    #[serde(skip)]
+    retryable: bool,
 }
 
 impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
@@ -24,6 +26,7 @@ impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
             milestone: None,
             labels: None,
             assignees: None,
+            retryable: false,
         }
     }
 
@@ -31,7 +34,10 @@ impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
     pub async fn send(self) -> crate::Result<models::issues::Issue> {
         let route = format!("/{}/issues", self.handler.repo);
 
-        self.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .crab
+            .send_mutation(http::Method::POST, route, Some(&self), self.retryable)
+            .await
     }
 
     /// The contents of the issue.
@@ -62,6 +68,14 @@ impl<'octo, 'r> CreateIssueBuilder<'octo, 'r> {
         self.assignees = assignees.into();
         self
     }
+
+    /// Allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`]. *NOTE: If GitHub processed the first attempt,
+    /// the issue is created twice.*
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +129,12 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListIssuesBuilder<'_, '_, '_, '_>,
+    models::issues::Issue,
+    |b| b.handler.crab
+}
+
 fn comma_separated<S: serde::Serializer>(
     labels: &Option<&[String]>,
     serializer: S,
@@ -46,6 +46,8 @@ impl<'octo, 'r> ListLabelsForIssueBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! { ListLabelsForIssueBuilder<'_, '_>, models::Label, |b| b.handler.crab }
+
 #[derive(serde::Serialize)]
 pub struct ListLabelsForRepoBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -84,3 +86,5 @@ impl<'octo, 'r> ListLabelsForRepoBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! { ListLabelsForRepoBuilder<'_, '_>, models::Label, |b| b.handler.crab }
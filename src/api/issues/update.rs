@@ -20,6 +20,8 @@ pub struct UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
     milestone: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     labels: Option<&'e [String]>,
+    #[serde(skip)]
+    retryable: bool,
 }
 
 impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
@@ -34,6 +36,7 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
             state_reason: None,
             milestone: None,
             labels: None,
+            retryable: false,
         }
     }
 
@@ -82,6 +85,13 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
         self
     }
 
+    /// Allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`].
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
     /// Send the actual request.
     pub async fn send(self) -> Result<models::issues::Issue> {
         let route = format!(
@@ -90,7 +100,10 @@ impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
             issue = self.number,
         );
 
-        self.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .crab
+            .send_mutation(http::Method::PATCH, route, Some(&self), self.retryable)
+            .await
     }
 }
 
@@ -242,6 +242,18 @@ impl<'octo> OrgHandler<'octo> {
         secrets::OrgSecretsHandler::new(self)
     }
 
+    /// Handle rulesets on the organization
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let rulesets = octocrab.orgs("org").rulesets().list().send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rulesets(&self) -> crate::rulesets::RulesetsHandler<'octo> {
+        crate::rulesets::RulesetsHandler::new(self.crab, format!("/orgs/{}", self.owner))
+    }
+
     /// ### Get interaction restrictions for an organization
     ///
     /// Shows which type of GitHub user can interact with this organization and when the restriction expires. If there is no restrictions, you will see an empty response.
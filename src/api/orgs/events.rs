@@ -90,3 +90,10 @@ impl<'octo, 'handler> ListOrgEventsBuilder<'octo, 'handler> {
         }
     }
 }
+
+crate::page::impl_paginate! {
+    ListOrgEventsBuilder<'_, '_>,
+    events::Event,
+    |b| b.handler.crab,
+    |b| Ok(b.send().await?.value.unwrap_or_default())
+}
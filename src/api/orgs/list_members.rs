@@ -36,3 +36,9 @@ impl<'octo, 'r> ListOrgMembersBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListOrgMembersBuilder<'_, '_>,
+    crate::models::Author,
+    |b| b.handler.crab
+}
@@ -67,6 +67,12 @@ impl<'octo, 'b> ListReposBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListReposBuilder<'_, '_>,
+    crate::models::Repository,
+    |b| b.handler.crab
+}
+
 #[cfg(test)]
 mod tests {
     #[tokio::test]
@@ -177,6 +177,12 @@ impl<'octo, 'r> ListUserProjectsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListUserProjectsBuilder<'_, '_>,
+    crate::models::Project,
+    |b| b.handler.crab
+}
+
 /// Helper builder struct to get a paged list of an organization's projects.
 ///
 /// Used by [`Octocrab::projects`].
@@ -231,6 +237,12 @@ impl<'octo, 'r> ListOrgProjectsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListOrgProjectsBuilder<'_, '_>,
+    crate::models::Project,
+    |b| b.handler.crab
+}
+
 /// Helper builder struct to create an organization project.
 ///
 /// Used by [`Octocrab::projects`].
@@ -315,6 +327,12 @@ impl<'octo, 'r> ListRepositoryProjectsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListRepositoryProjectsBuilder<'_, '_>,
+    crate::models::Project,
+    |b| b.handler.crab
+}
+
 pub struct Named;
 pub struct NotNamed;
 
@@ -560,6 +560,12 @@ impl<'octo, 'r> ListReviewsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListReviewsBuilder<'_, '_>,
+    crate::models::pulls::Review,
+    |b| b.handler.crab
+}
+
 #[derive(serde::Serialize)]
 pub struct ListReviewsBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -597,23 +603,6 @@ impl PullRequestHandler<'_> {
         R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
     }
 
-    pub(crate) async fn http_post<R, A, P>(&self, route: A, body: Option<&P>) -> crate::Result<R>
-    where
-        A: AsRef<str>,
-        P: serde::Serialize + ?Sized,
-        R: crate::FromResponse,
-    {
-        let uri = Uri::builder()
-            .path_and_query(route.as_ref())
-            .build()
-            .context(HttpSnafu)?;
-        let mut request = Builder::new().method(Method::POST).uri(uri);
-        request = self.build_request(request);
-        let request = self.crab.build_request(request, body)?;
-
-        R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
-    }
-
     pub(crate) async fn http_put<R, A, P>(&self, route: A, body: Option<&P>) -> crate::Result<R>
     where
         A: AsRef<str>,
@@ -632,7 +621,15 @@ impl PullRequestHandler<'_> {
         R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
     }
 
-    pub(crate) async fn http_patch<R, A, P>(&self, route: A, body: Option<&P>) -> crate::Result<R>
+    /// Sends a `POST` or `PATCH` request, marked as [`crate::Retryable`] if
+    /// `retryable` is set.
+    pub(crate) async fn http_mutation<R, A, P>(
+        &self,
+        method: Method,
+        route: A,
+        body: Option<&P>,
+        retryable: bool,
+    ) -> crate::Result<R>
     where
         A: AsRef<str>,
         P: serde::Serialize + ?Sized,
@@ -642,7 +639,10 @@ impl PullRequestHandler<'_> {
             .path_and_query(route.as_ref())
             .build()
             .context(HttpSnafu)?;
-        let mut request = Builder::new().method(Method::PATCH).uri(uri);
+        let mut request = Builder::new().method(method).uri(uri);
+        if retryable {
+            request = request.extension(crate::Retryable);
+        }
 
         request = self.build_request(request);
         let request = self.crab.build_request(request, body)?;
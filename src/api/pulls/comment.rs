@@ -88,6 +88,12 @@ impl<'octo, 'b> ListCommentsBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListCommentsBuilder<'_, '_>,
+    crate::models::pulls::Comment,
+    |b| b.handler.crab
+}
+
 /// A builder pattern struct for working with specific comment.
 ///
 /// created by [`PullRequestHandler::comment`]
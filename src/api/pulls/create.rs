@@ -15,6 +15,8 @@ pub struct CreatePullRequestBuilder<'octo, 'b> {
     maintainer_can_modify: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     head_repo: Option<String>,
+    #[serde(skip)]
+    retryable: bool,
 }
 
 impl<'octo, 'b> CreatePullRequestBuilder<'octo, 'b> {
@@ -33,6 +35,7 @@ impl<'octo, 'b> CreatePullRequestBuilder<'octo, 'b> {
             draft: None,
             maintainer_can_modify: None,
             head_repo: None,
+            retryable: false,
         }
     }
 
@@ -59,6 +62,14 @@ impl<'octo, 'b> CreatePullRequestBuilder<'octo, 'b> {
         self
     }
 
+    /// Allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`]. *NOTE: If GitHub processed the first attempt,
+    /// the pull request is created twice.*
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
     /// Sends the request to create the pull request.
     pub async fn send(self) -> crate::Result<crate::models::pulls::PullRequest> {
         let route = format!(
@@ -67,7 +78,9 @@ impl<'octo, 'b> CreatePullRequestBuilder<'octo, 'b> {
             repo = self.handler.repo
         );
 
-        self.handler.http_post(route, Some(&self)).await
+        self.handler
+            .http_mutation(http::Method::POST, route, Some(&self), self.retryable)
+            .await
     }
 }
 
@@ -98,6 +98,12 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListPullRequestsBuilder<'_, '_>,
+    crate::models::pulls::SimplePullRequest,
+    |b| b.handler.crab
+}
+
 #[cfg(test)]
 mod tests {
     #[tokio::test]
@@ -49,3 +49,9 @@ impl<'octo, 'r> SpecificPullRequestCommitBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    SpecificPullRequestCommitBuilder<'_, '_>,
+    RepoCommit,
+    |b| b.handler.crab
+}
@@ -51,3 +51,9 @@ impl<'octo, 'b> ListReviewCommentsBuilder<'octo, 'b> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListReviewCommentsBuilder<'_, '_>,
+    crate::models::pulls::ReviewComment,
+    |b| b.handler.crab
+}
@@ -15,6 +15,8 @@ pub struct UpdatePullRequestBuilder<'octo, 'b> {
     body: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     maintainer_can_modify: Option<bool>,
+    #[serde(skip)]
+    retryable: bool,
 }
 
 impl<'octo, 'b> UpdatePullRequestBuilder<'octo, 'b> {
@@ -30,6 +32,7 @@ impl<'octo, 'b> UpdatePullRequestBuilder<'octo, 'b> {
             state: None,
             body: None,
             maintainer_can_modify: None,
+            retryable: false,
         }
     }
 
@@ -66,6 +69,13 @@ impl<'octo, 'b> UpdatePullRequestBuilder<'octo, 'b> {
         self
     }
 
+    /// Allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`].
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
     /// Sends the request to update the pull request.
     pub async fn send(self) -> crate::Result<crate::models::pulls::PullRequest> {
         let route = format!(
@@ -75,7 +85,9 @@ impl<'octo, 'b> UpdatePullRequestBuilder<'octo, 'b> {
             pr = self.pull_number,
         );
 
-        self.handler.http_patch(route, Some(&self)).await
+        self.handler
+            .http_mutation(http::Method::PATCH, route, Some(&self), self.retryable)
+            .await
     }
 }
 
@@ -7,15 +7,19 @@ use http::Uri;
 use http_body_util::combinators::BoxBody;
 use snafu::ResultExt;
 
+pub mod branch_protection;
 mod branches;
 mod collaborators;
 mod commits;
 mod contributors;
 mod dependabot;
+pub mod deployments;
+pub mod environments;
 pub mod events;
 mod file;
 pub mod forks;
 mod generate;
+mod git;
 mod merges;
 mod pulls;
 pub mod release_assets;
@@ -38,13 +42,17 @@ use crate::repos::collaborators::GetCollaboratorPermissionBuilder;
 use crate::repos::file::GetReadmeBuilder;
 use crate::repos::variables::RepoVariablesHandler;
 use crate::{models, params, Octocrab, Result};
+pub use branch_protection::BranchProtectionHandler;
 pub use branches::ListBranchesBuilder;
 pub use collaborators::ListCollaboratorsBuilder;
 pub use commits::ListCommitsBuilder;
 pub use contributors::ListContributorsBuilder;
 pub use dependabot::RepoDependabotAlertsHandler;
+pub use deployments::DeploymentsHandler;
+pub use environments::RepoEnvironmentsHandler;
 pub use file::{DeleteFileBuilder, GetContentBuilder, UpdateFileBuilder};
 pub use generate::GenerateRepositoryBuilder;
+pub use git::{CommitChangesBuilder, CreateTreeBuilder, GetTreeBuilder};
 pub use merges::MergeBranchBuilder;
 pub use pulls::ListPullsBuilder;
 pub use release_assets::ReleaseAssetsHandler;
@@ -241,6 +249,127 @@ impl<'octo> RepoHandler<'octo> {
             .map(drop)
     }
 
+    /// Updates an existing reference to point to the object `sha`. Unless
+    /// `force` is `true`, the update must be a fast-forward.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let commit_sha = "";
+    /// use octocrab::params::repos::Reference;
+    ///
+    /// let main = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .update_ref(&Reference::Branch("main".to_string()), commit_sha, false)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_ref(
+        &self,
+        reference: &params::repos::Reference,
+        sha: impl Into<String>,
+        force: bool,
+    ) -> Result<models::repos::Ref> {
+        let route = format!(
+            "/{repo}/git/refs/{ref}",
+            repo = self.repo,
+            ref = reference.ref_url()
+        );
+        self.crab
+            .patch(
+                route,
+                Some(&serde_json::json!({
+                    "sha": sha.into(),
+                    "force": force,
+                })),
+            )
+            .await
+    }
+
+    /// Creates a blob in the Git database with `content`, which is base64
+    /// encoded automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blob = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_blob(b"\x89PNG\r\n")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_blob(
+        &self,
+        content: impl AsRef<[u8]>,
+    ) -> Result<models::repos::git::BlobRef> {
+        use base64::Engine;
+        let route = format!("/{}/git/blobs", self.repo);
+        self.crab
+            .post(
+                route,
+                Some(&serde_json::json!({
+                    "content": base64::prelude::BASE64_STANDARD.encode(content),
+                    "encoding": "base64",
+                })),
+            )
+            .await
+    }
+
+    /// Gets a blob from the Git database.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blob = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_blob("3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_blob(&self, sha: impl AsRef<str>) -> Result<models::repos::git::Blob> {
+        let route = format!("/{}/git/blobs/{}", self.repo, sha.as_ref());
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a tree in the Git database with the `entries`, on top of an
+    /// optional base tree.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let base_tree_sha = "";
+    /// use octocrab::models::repos::git::{FileMode, TreeEntryUpdate};
+    ///
+    /// let tree = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_tree(vec![
+    ///         TreeEntryUpdate::content("README.md", FileMode::File, "# Hello\n"),
+    ///         TreeEntryUpdate::delete("obsolete.txt"),
+    ///     ])
+    ///     .base_tree(base_tree_sha)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_tree(
+        &self,
+        entries: Vec<models::repos::git::TreeEntryUpdate>,
+    ) -> CreateTreeBuilder<'_, '_> {
+        CreateTreeBuilder::new(self, entries)
+    }
+
+    /// Gets a tree from the Git database.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let tree = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312")
+    ///     .recursive(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_tree(&self, sha: impl Into<String>) -> GetTreeBuilder<'_, '_> {
+        GetTreeBuilder::new(self, sha.into())
+    }
+
     /// Get repository content.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -445,6 +574,69 @@ impl<'octo> RepoHandler<'octo> {
         ListBranchesBuilder::new(self)
     }
 
+    /// Gets a branch with its protection.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let branch = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_branch("main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_branch(&self, branch: impl AsRef<str>) -> Result<models::repos::Branch> {
+        let route = format!(
+            "/{repo}/branches/{branch}",
+            repo = self.repo,
+            branch = branch.as_ref(),
+        );
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Renames a branch, updating the pull requests, branch protections and
+    /// redirects referring to it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let branch = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .rename_branch("master", "main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rename_branch(
+        &self,
+        branch: impl AsRef<str>,
+        new_name: impl AsRef<str>,
+    ) -> Result<models::repos::Branch> {
+        let route = format!(
+            "/{repo}/branches/{branch}/rename",
+            repo = self.repo,
+            branch = branch.as_ref(),
+        );
+        let body = serde_json::json!({ "new_name": new_name.as_ref() });
+        self.crab.post(route, Some(&body)).await
+    }
+
+    /// Creates a [`BranchProtectionHandler`] for the classic protection of
+    /// `branch`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let protection = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .get()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn branch_protection(
+        &self,
+        branch: impl Into<String>,
+    ) -> branch_protection::BranchProtectionHandler<'_, '_> {
+        branch_protection::BranchProtectionHandler::new(self, branch.into())
+    }
+
     /// List commits from a repository
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -543,6 +735,11 @@ impl<'octo> RepoHandler<'octo> {
         releases::ReleasesHandler::new(self)
     }
 
+    /// Creates a `DeploymentsHandler` for the specified repository.
+    pub fn deployments(&self) -> deployments::DeploymentsHandler<'_, '_> {
+        deployments::DeploymentsHandler::new(self)
+    }
+
     /// Create a status for a specified commit in the specified repository.
     pub fn create_status(
         &self,
@@ -780,6 +977,39 @@ impl<'octo> RepoHandler<'octo> {
         RepoVariablesHandler::new(self)
     }
 
+    /// Handle environments on the repository
+    pub fn environments(&self) -> RepoEnvironmentsHandler<'_> {
+        RepoEnvironmentsHandler::new(self)
+    }
+
+    /// Handle rulesets on the repository
+    pub fn rulesets(&self) -> crate::rulesets::RulesetsHandler<'octo> {
+        crate::rulesets::RulesetsHandler::new(self.crab, format!("/{}", self.repo))
+    }
+
+    /// Creates a new [`ListBranchRulesBuilder`](crate::rulesets::ListBranchRulesBuilder)
+    /// to list the rules applying to `branch`, from the rulesets of the
+    /// repository and of its organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let rules = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .branch_rules("main")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn branch_rules(
+        &self,
+        branch: impl AsRef<str>,
+    ) -> crate::rulesets::ListBranchRulesBuilder<'octo> {
+        crate::rulesets::ListBranchRulesBuilder::new(
+            self.crab,
+            format!("/{}/rules/branches/{}", self.repo, branch.as_ref()),
+        )
+    }
+
     /// Handle dependabot alerts on the repository
     pub fn dependabot(&self) -> RepoDependabotAlertsHandler<'_> {
         RepoDependabotAlertsHandler::new(self)
@@ -824,6 +1054,29 @@ impl<'octo> RepoHandler<'octo> {
         )
     }
 
+    /// Commits changes to several files onto `branch` at once, creating the
+    /// blobs, tree and commit and then moving the branch to the new commit.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let commit = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .commit_changes("main", "Rename the crab")
+    ///     .write("crabs/ferris.txt", "Ferris\n")
+    ///     .write("crabs/corro.txt", "Corro\n")
+    ///     .delete("crabs/crab.txt")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn commit_changes(
+        &self,
+        branch: impl Into<String>,
+        message: impl Into<String>,
+    ) -> CommitChangesBuilder<'_, '_> {
+        CommitChangesBuilder::new(self, branch.into(), message.into())
+    }
+
     /// ### Get interaction restrictions for a repository
     ///
     /// Shows which type of GitHub user can interact with this repository and when the restriction expires. If there is no restrictions, you will see an empty response.
@@ -0,0 +1,526 @@
+use super::*;
+use crate::models::apps::App;
+use crate::models::repos::branch_protection::{
+    BranchProtection, BranchRestrictions, ProtectionSetting, RequiredPullRequestReviews,
+    RequiredPullRequestReviewsUpdate, RequiredStatusChecks, RequiredStatusChecksUpdate,
+    RestrictionsUpdate, StatusCheck,
+};
+use crate::models::teams::Team;
+use crate::models::Author;
+
+/// Handler for GitHub's classic branch protection API.
+///
+/// Created with [`RepoHandler::branch_protection`].
+pub struct BranchProtectionHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+    branch: String,
+}
+
+impl<'octo, 'r> BranchProtectionHandler<'octo, 'r> {
+    pub(crate) fn new(parent: &'r RepoHandler<'octo>, branch: String) -> Self {
+        Self {
+            handler: parent,
+            branch,
+        }
+    }
+
+    /// Gets the protection of the branch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let protection = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .get()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> Result<BranchProtection> {
+        self.handler.crab.get(self.route(), None::<&()>).await
+    }
+
+    /// Creates a new [`UpdateBranchProtectionBuilder`] to replace the whole
+    /// protection of the branch. The required status checks, admin
+    /// enforcement, required reviews and push restrictions which are not set
+    /// are disabled.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::branch_protection::{
+    ///     RequiredPullRequestReviewsUpdate, RequiredStatusChecksUpdate, StatusCheck,
+    /// };
+    ///
+    /// let protection = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .update()
+    ///     .required_status_checks(RequiredStatusChecksUpdate {
+    ///         strict: true,
+    ///         checks: vec![StatusCheck::new("ci/test", None)],
+    ///     })
+    ///     .enforce_admins(true)
+    ///     .required_pull_request_reviews(RequiredPullRequestReviewsUpdate {
+    ///         required_approving_review_count: Some(1),
+    ///         ..Default::default()
+    ///     })
+    ///     .required_linear_history(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self) -> UpdateBranchProtectionBuilder<'_, '_, '_> {
+        UpdateBranchProtectionBuilder::new(self)
+    }
+
+    /// Deletes the protection of the branch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .delete()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self) -> Result<()> {
+        self.delete_setting("").await
+    }
+
+    /// Gets whether the protection is enforced for administrators.
+    pub async fn get_admin_enforcement(&self) -> Result<ProtectionSetting> {
+        let route = format!("{}/enforce_admins", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Enforces the protection for administrators.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let admin_enforcement = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .enable_admin_enforcement()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_admin_enforcement(&self) -> Result<ProtectionSetting> {
+        let route = format!("{}/enforce_admins", self.route());
+        self.handler.crab.post(route, None::<&()>).await
+    }
+
+    /// Stops enforcing the protection for administrators.
+    pub async fn disable_admin_enforcement(&self) -> Result<()> {
+        self.delete_setting("/enforce_admins").await
+    }
+
+    /// Gets whether commits pushed to the branch must have verified
+    /// signatures.
+    pub async fn get_signatures(&self) -> Result<ProtectionSetting> {
+        let route = format!("{}/required_signatures", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Requires commits pushed to the branch to have verified signatures.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let signatures = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .enable_signatures()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_signatures(&self) -> Result<ProtectionSetting> {
+        let route = format!("{}/required_signatures", self.route());
+        self.handler.crab.post(route, None::<&()>).await
+    }
+
+    /// Stops requiring commits pushed to the branch to have verified
+    /// signatures.
+    pub async fn disable_signatures(&self) -> Result<()> {
+        self.delete_setting("/required_signatures").await
+    }
+
+    /// Gets the status checks which must pass before merging into the branch.
+    pub async fn get_required_status_checks(&self) -> Result<RequiredStatusChecks> {
+        let route = format!("{}/required_status_checks", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a new [`UpdateRequiredStatusChecksBuilder`] to update the
+    /// status checks which must pass before merging into the branch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::branch_protection::StatusCheck;
+    ///
+    /// let checks = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .update_required_status_checks()
+    ///     .strict(true)
+    ///     .checks(vec![StatusCheck::new("ci/test", None)])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_required_status_checks(&self) -> UpdateRequiredStatusChecksBuilder<'_, '_, '_> {
+        UpdateRequiredStatusChecksBuilder::new(self)
+    }
+
+    /// Stops requiring status checks to pass before merging into the branch.
+    pub async fn remove_required_status_checks(&self) -> Result<()> {
+        self.delete_setting("/required_status_checks").await
+    }
+
+    /// Gets the contexts of the status checks which must pass before merging
+    /// into the branch.
+    pub async fn get_status_check_contexts(&self) -> Result<Vec<String>> {
+        let route = format!("{}/required_status_checks/contexts", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Adds the contexts of status checks which must pass, returning all the
+    /// required contexts.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let contexts = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .add_status_check_contexts(vec!["ci/lint".to_string()])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_status_check_contexts(&self, contexts: Vec<String>) -> Result<Vec<String>> {
+        let route = format!("{}/required_status_checks/contexts", self.route());
+        let body = serde_json::json!({ "contexts": contexts });
+        self.handler.crab.post(route, Some(&body)).await
+    }
+
+    /// Replaces the contexts of the status checks which must pass.
+    pub async fn set_status_check_contexts(&self, contexts: Vec<String>) -> Result<Vec<String>> {
+        let route = format!("{}/required_status_checks/contexts", self.route());
+        let body = serde_json::json!({ "contexts": contexts });
+        self.handler.crab.put(route, Some(&body)).await
+    }
+
+    /// Removes contexts of the status checks which must pass, returning the
+    /// remaining required contexts.
+    pub async fn remove_status_check_contexts(&self, contexts: Vec<String>) -> Result<Vec<String>> {
+        let route = format!("{}/required_status_checks/contexts", self.route());
+        let body = serde_json::json!({ "contexts": contexts });
+        self.handler.crab.delete(route, Some(&body)).await
+    }
+
+    /// Gets the reviews which pull requests need before merging into the
+    /// branch.
+    pub async fn get_pull_request_reviews(&self) -> Result<RequiredPullRequestReviews> {
+        let route = format!("{}/required_pull_request_reviews", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Updates the reviews which pull requests need before merging into the
+    /// branch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::branch_protection::RequiredPullRequestReviewsUpdate;
+    ///
+    /// let reviews = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .update_pull_request_reviews(&RequiredPullRequestReviewsUpdate {
+    ///         dismiss_stale_reviews: Some(true),
+    ///         required_approving_review_count: Some(2),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_pull_request_reviews(
+        &self,
+        reviews: &RequiredPullRequestReviewsUpdate,
+    ) -> Result<RequiredPullRequestReviews> {
+        let route = format!("{}/required_pull_request_reviews", self.route());
+        self.handler.crab.patch(route, Some(reviews)).await
+    }
+
+    /// Stops requiring reviews before merging into the branch.
+    pub async fn delete_pull_request_reviews(&self) -> Result<()> {
+        self.delete_setting("/required_pull_request_reviews").await
+    }
+
+    /// Gets the users, teams and apps which can push to the branch, for
+    /// organization repositories.
+    pub async fn get_restrictions(&self) -> Result<BranchRestrictions> {
+        let route = format!("{}/restrictions", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Lets anyone with push access to the repository push to the branch.
+    pub async fn delete_restrictions(&self) -> Result<()> {
+        self.delete_setting("/restrictions").await
+    }
+
+    /// Lets the users with these logins push to the branch, returning all the
+    /// users which can.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let users = octocrab.repos("owner", "repo")
+    ///     .branch_protection("main")
+    ///     .add_user_restrictions(vec!["octocat".to_string()])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_user_restrictions(&self, users: Vec<String>) -> Result<Vec<Author>> {
+        self.restrict(http::Method::POST, "users", users).await
+    }
+
+    /// Replaces the users which can push to the branch.
+    pub async fn set_user_restrictions(&self, users: Vec<String>) -> Result<Vec<Author>> {
+        self.restrict(http::Method::PUT, "users", users).await
+    }
+
+    /// Stops letting the users with these logins push to the branch,
+    /// returning the remaining users which can.
+    pub async fn remove_user_restrictions(&self, users: Vec<String>) -> Result<Vec<Author>> {
+        self.restrict(http::Method::DELETE, "users", users).await
+    }
+
+    /// Lets the teams with these slugs push to the branch, returning all the
+    /// teams which can.
+    pub async fn add_team_restrictions(&self, teams: Vec<String>) -> Result<Vec<Team>> {
+        self.restrict(http::Method::POST, "teams", teams).await
+    }
+
+    /// Replaces the teams which can push to the branch.
+    pub async fn set_team_restrictions(&self, teams: Vec<String>) -> Result<Vec<Team>> {
+        self.restrict(http::Method::PUT, "teams", teams).await
+    }
+
+    /// Stops letting the teams with these slugs push to the branch, returning
+    /// the remaining teams which can.
+    pub async fn remove_team_restrictions(&self, teams: Vec<String>) -> Result<Vec<Team>> {
+        self.restrict(http::Method::DELETE, "teams", teams).await
+    }
+
+    /// Lets the apps with these slugs push to the branch, returning all the
+    /// apps which can.
+    pub async fn add_app_restrictions(&self, apps: Vec<String>) -> Result<Vec<App>> {
+        self.restrict(http::Method::POST, "apps", apps).await
+    }
+
+    /// Replaces the apps which can push to the branch.
+    pub async fn set_app_restrictions(&self, apps: Vec<String>) -> Result<Vec<App>> {
+        self.restrict(http::Method::PUT, "apps", apps).await
+    }
+
+    /// Stops letting the apps with these slugs push to the branch, returning
+    /// the remaining apps which can.
+    pub async fn remove_app_restrictions(&self, apps: Vec<String>) -> Result<Vec<App>> {
+        self.restrict(http::Method::DELETE, "apps", apps).await
+    }
+
+    fn route(&self) -> String {
+        format!("/{}/branches/{}/protection", self.handler.repo, self.branch)
+    }
+
+    async fn delete_setting(&self, setting: &str) -> Result<()> {
+        let route = format!("{}{setting}", self.route());
+        let resp = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await?;
+        Ok(())
+    }
+
+    async fn restrict<R: crate::FromResponse>(
+        &self,
+        method: http::Method,
+        kind: &str,
+        names: Vec<String>,
+    ) -> Result<R> {
+        let route = format!("{}/restrictions/{kind}", self.route());
+        let body = serde_json::json!({ kind: names });
+        match method {
+            http::Method::POST => self.handler.crab.post(route, Some(&body)).await,
+            http::Method::PUT => self.handler.crab.put(route, Some(&body)).await,
+            _ => self.handler.crab.delete(route, Some(&body)).await,
+        }
+    }
+}
+
+/// A builder pattern struct for replacing the protection of a branch.
+///
+/// created by [`BranchProtectionHandler::update`]
+#[derive(serde::Serialize)]
+pub struct UpdateBranchProtectionBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 BranchProtectionHandler<'octo, 'r1>,
+    required_status_checks: Option<RequiredStatusChecksUpdate>,
+    enforce_admins: Option<bool>,
+    required_pull_request_reviews: Option<RequiredPullRequestReviewsUpdate>,
+    restrictions: Option<RestrictionsUpdate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_linear_history: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_force_pushes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_deletions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_creations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_conversation_resolution: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_branch: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_fork_syncing: Option<bool>,
+}
+
+impl<'octo, 'r1, 'r2> UpdateBranchProtectionBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 BranchProtectionHandler<'octo, 'r1>) -> Self {
+        Self {
+            handler,
+            required_status_checks: None,
+            enforce_admins: None,
+            required_pull_request_reviews: None,
+            restrictions: None,
+            required_linear_history: None,
+            allow_force_pushes: None,
+            allow_deletions: None,
+            block_creations: None,
+            required_conversation_resolution: None,
+            lock_branch: None,
+            allow_fork_syncing: None,
+        }
+    }
+
+    /// Requires status checks to pass before merging.
+    pub fn required_status_checks(mut self, checks: RequiredStatusChecksUpdate) -> Self {
+        self.required_status_checks = Some(checks);
+        self
+    }
+
+    /// Whether the protection is enforced for administrators.
+    pub fn enforce_admins(mut self, enforce_admins: impl Into<bool>) -> Self {
+        self.enforce_admins = Some(enforce_admins.into());
+        self
+    }
+
+    /// Requires pull requests to be reviewed before merging.
+    pub fn required_pull_request_reviews(
+        mut self,
+        reviews: RequiredPullRequestReviewsUpdate,
+    ) -> Self {
+        self.required_pull_request_reviews = Some(reviews);
+        self
+    }
+
+    /// Only lets these users, teams and apps push to the branch, for
+    /// organization repositories.
+    pub fn restrictions(mut self, restrictions: RestrictionsUpdate) -> Self {
+        self.restrictions = Some(restrictions);
+        self
+    }
+
+    /// Prevents merge commits from being pushed to the branch.
+    pub fn required_linear_history(mut self, required_linear_history: impl Into<bool>) -> Self {
+        self.required_linear_history = Some(required_linear_history.into());
+        self
+    }
+
+    /// Lets users with push access force push to the branch.
+    pub fn allow_force_pushes(mut self, allow_force_pushes: impl Into<bool>) -> Self {
+        self.allow_force_pushes = Some(allow_force_pushes.into());
+        self
+    }
+
+    /// Lets users with push access delete the branch.
+    pub fn allow_deletions(mut self, allow_deletions: impl Into<bool>) -> Self {
+        self.allow_deletions = Some(allow_deletions.into());
+        self
+    }
+
+    /// Only lets the users, teams and apps with push access create matching
+    /// branches.
+    pub fn block_creations(mut self, block_creations: impl Into<bool>) -> Self {
+        self.block_creations = Some(block_creations.into());
+        self
+    }
+
+    /// Requires all the conversations on code to be resolved before merging.
+    pub fn required_conversation_resolution(
+        mut self,
+        required_conversation_resolution: impl Into<bool>,
+    ) -> Self {
+        self.required_conversation_resolution = Some(required_conversation_resolution.into());
+        self
+    }
+
+    /// Makes the branch read-only.
+    pub fn lock_branch(mut self, lock_branch: impl Into<bool>) -> Self {
+        self.lock_branch = Some(lock_branch.into());
+        self
+    }
+
+    /// Lets users pull changes from upstream when the branch is locked.
+    pub fn allow_fork_syncing(mut self, allow_fork_syncing: impl Into<bool>) -> Self {
+        self.allow_fork_syncing = Some(allow_fork_syncing.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<BranchProtection> {
+        self.handler
+            .handler
+            .crab
+            .put(self.handler.route(), Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for updating the required status checks of a
+/// branch.
+///
+/// created by [`BranchProtectionHandler::update_required_status_checks`]
+#[derive(serde::Serialize)]
+pub struct UpdateRequiredStatusChecksBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 BranchProtectionHandler<'octo, 'r1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checks: Option<Vec<StatusCheck>>,
+}
+
+impl<'octo, 'r1, 'r2> UpdateRequiredStatusChecksBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 BranchProtectionHandler<'octo, 'r1>) -> Self {
+        Self {
+            handler,
+            strict: None,
+            checks: None,
+        }
+    }
+
+    /// Whether branches must be up to date with the base branch before
+    /// merging.
+    pub fn strict(mut self, strict: impl Into<bool>) -> Self {
+        self.strict = Some(strict.into());
+        self
+    }
+
+    /// The status checks which must pass.
+    pub fn checks(mut self, checks: impl Into<Vec<StatusCheck>>) -> Self {
+        self.checks = Some(checks.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<RequiredStatusChecks> {
+        let route = format!("{}/required_status_checks", self.handler.route());
+        self.handler.handler.crab.patch(route, Some(&self)).await
+    }
+}
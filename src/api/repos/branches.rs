@@ -48,3 +48,9 @@ impl<'octo, 'r> ListBranchesBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListBranchesBuilder<'_, '_>,
+    models::repos::Branch,
+    |b| b.handler.crab
+}
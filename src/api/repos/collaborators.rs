@@ -50,6 +50,12 @@ impl<'octo, 'r> ListCollaboratorsBuilder<'octo, 'r> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListCollaboratorsBuilder<'_, '_>,
+    crate::models::Collaborator,
+    |b| b.handler.crab
+}
+
 #[derive(serde::Serialize)]
 pub struct GetCollaboratorPermissionBuilder<'octo, 'r> {
     #[serde(skip)]
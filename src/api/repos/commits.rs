@@ -97,3 +97,9 @@ impl<'octo, 'r> ListCommitsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListCommitsBuilder<'_, '_>,
+    crate::models::repos::RepoCommit,
+    |b| b.handler.crab
+}
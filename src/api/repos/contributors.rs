@@ -46,3 +46,9 @@ impl<'octo, 'r> ListContributorsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListContributorsBuilder<'_, '_>,
+    crate::models::Contributor,
+    |b| b.handler.crab
+}
@@ -0,0 +1,612 @@
+use super::*;
+use crate::models::repos::deployments::{
+    CreateDeploymentResponse, Deployment, DeploymentState, DeploymentStatus,
+    ProtectionRuleReviewState,
+};
+use crate::models::{DeploymentId, DeploymentStatusId, RunId};
+use crate::FromResponse;
+
+/// Handler for GitHub's deployments API.
+///
+/// Created with [`RepoHandler::deployments`].
+pub struct DeploymentsHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> DeploymentsHandler<'octo, 'r> {
+    pub(crate) fn new(parent: &'r RepoHandler<'octo>) -> Self {
+        Self { handler: parent }
+    }
+
+    /// Creates a new [`ListDeploymentsBuilder`] that can be configured to
+    /// filter listing deployments.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let page = octocrab.repos("owner", "repo")
+    ///     .deployments()
+    ///     .list()
+    ///     // Optional Parameters
+    ///     .environment("production")
+    ///     .per_page(100)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListDeploymentsBuilder<'_, '_, '_> {
+        ListDeploymentsBuilder::new(self)
+    }
+
+    /// Creates a new [`CreateDeploymentBuilder`] to deploy `reference`, which
+    /// can be a branch, tag or SHA.
+    ///
+    /// If the ref is behind the default branch, GitHub merges the default
+    /// branch into it instead of creating the deployment, unless
+    /// [`auto_merge`](CreateDeploymentBuilder::auto_merge) is disabled.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::deployments::CreateDeploymentResponse;
+    ///
+    /// let response = octocrab.repos("owner", "repo")
+    ///     .deployments()
+    ///     .create("main")
+    ///     // Optional Parameters
+    ///     .environment("production")
+    ///     .auto_merge(false)
+    ///     .required_contexts(Vec::new())
+    ///     .payload(serde_json::json!({ "deploy": "migrate" }))
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if let CreateDeploymentResponse::Created(deployment) = response {
+    ///     println!("Created deployment {}", deployment.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, reference: impl Into<String>) -> CreateDeploymentBuilder<'_, '_, '_> {
+        CreateDeploymentBuilder::new(self, reference.into())
+    }
+
+    /// Gets a deployment using its id.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let deployment = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .get(42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: DeploymentId) -> Result<Deployment> {
+        let route = format!("/{}/deployments/{id}", self.handler.repo);
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Deletes a deployment using its id. Only inactive deployments can be
+    /// deleted, unless it is the only deployment of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .delete(42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, id: DeploymentId) -> Result<()> {
+        let route = format!("/{}/deployments/{id}", self.handler.repo);
+
+        crate::map_github_error(self.handler.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Creates a new [`ListDeploymentStatusesBuilder`] for the statuses of
+    /// deployment `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .list_statuses(42u64.into())
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_statuses(&self, id: DeploymentId) -> ListDeploymentStatusesBuilder<'_, '_, '_> {
+        ListDeploymentStatusesBuilder::new(self, id)
+    }
+
+    /// Creates a new [`CreateDeploymentStatusBuilder`] to set the `state` of
+    /// deployment `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::deployments::DeploymentState;
+    ///
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .create_status(42u64.into(), DeploymentState::Success)
+    ///     // Optional Parameters
+    ///     .log_url("https://ci.example.com/deploy/42")
+    ///     .environment_url("https://example.com")
+    ///     .description("Deployed!")
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_status(
+        &self,
+        id: DeploymentId,
+        state: DeploymentState,
+    ) -> CreateDeploymentStatusBuilder<'_, '_, '_> {
+        CreateDeploymentStatusBuilder::new(self, id, state)
+    }
+
+    /// Gets status `status_id` of deployment `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .get_status(42u64.into(), 1u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_status(
+        &self,
+        id: DeploymentId,
+        status_id: DeploymentStatusId,
+    ) -> Result<DeploymentStatus> {
+        let route = format!(
+            "/{}/deployments/{id}/statuses/{status_id}",
+            self.handler.repo
+        );
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a new [`ReviewProtectionRuleBuilder`] to approve or reject
+    /// the deployments of workflow run `run_id` to `environment_name`, which
+    /// wait on the custom deployment protection rule of your GitHub App.
+    ///
+    /// The run is the one of the `deployment_callback_url` of the
+    /// `deployment_protection_rule` webhook event.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::deployments::ProtectionRuleReviewState;
+    ///
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .review_protection_rule(
+    ///         1234u64.into(),
+    ///         "production",
+    ///         ProtectionRuleReviewState::Approved,
+    ///     )
+    ///     .comment("All health checks passed.")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn review_protection_rule(
+        &self,
+        run_id: RunId,
+        environment_name: impl Into<String>,
+        state: ProtectionRuleReviewState,
+    ) -> ReviewProtectionRuleBuilder<'_, '_, '_> {
+        ReviewProtectionRuleBuilder::new(self, run_id, environment_name.into(), state)
+    }
+}
+
+/// A builder pattern struct for listing deployments.
+///
+/// created by [`DeploymentsHandler::list`]
+#[derive(serde::Serialize)]
+pub struct ListDeploymentsBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r1, 'r2> ListDeploymentsBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 DeploymentsHandler<'octo, 'r1>) -> Self {
+        Self {
+            handler,
+            sha: None,
+            reference: None,
+            task: None,
+            environment: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list deployments of the commit `sha`.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    /// Only list deployments of `reference`, a branch, tag or SHA.
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Only list deployments of `task`, e.g. `deploy` or `deploy:migrations`.
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    /// Only list deployments to `environment`, e.g. `production`.
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<Deployment>> {
+        let route = format!("/{}/deployments", self.handler.handler.repo);
+        self.handler.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+crate::page::impl_paginate! {
+    ListDeploymentsBuilder<'_, '_, '_>,
+    Deployment,
+    |b| b.handler.handler.crab
+}
+
+/// A builder pattern struct for creating deployments.
+///
+/// created by [`DeploymentsHandler::create`]
+#[derive(serde::Serialize)]
+pub struct CreateDeploymentBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(rename = "ref")]
+    reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_contexts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payload: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transient_environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    production_environment: Option<bool>,
+}
+
+impl<'octo, 'r1, 'r2> CreateDeploymentBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 DeploymentsHandler<'octo, 'r1>, reference: String) -> Self {
+        Self {
+            handler,
+            reference,
+            task: None,
+            auto_merge: None,
+            required_contexts: None,
+            payload: None,
+            environment: None,
+            description: None,
+            transient_environment: None,
+            production_environment: None,
+        }
+    }
+
+    /// The task to execute, e.g. `deploy:migrations`. Defaults to `deploy`.
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    /// Whether to merge the default branch into the ref first, if it is
+    /// behind. Defaults to `true`.
+    pub fn auto_merge(mut self, auto_merge: impl Into<bool>) -> Self {
+        self.auto_merge = Some(auto_merge.into());
+        self
+    }
+
+    /// The status contexts to verify against commit status checks. An empty
+    /// list bypasses the checks. Defaults to all unique contexts.
+    pub fn required_contexts(mut self, required_contexts: impl Into<Vec<String>>) -> Self {
+        self.required_contexts = Some(required_contexts.into());
+        self
+    }
+
+    /// Extra information for the deployment system, as a JSON object or
+    /// string.
+    pub fn payload(mut self, payload: impl Into<serde_json::Value>) -> Self {
+        self.payload = Some(payload.into());
+        self
+    }
+
+    /// The name of the target environment. Defaults to `production`.
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// A short description of the deployment.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the environment will no longer exist at some point in the
+    /// future. Defaults to `false`.
+    pub fn transient_environment(mut self, transient_environment: impl Into<bool>) -> Self {
+        self.transient_environment = Some(transient_environment.into());
+        self
+    }
+
+    /// Whether the environment is one with which end-users directly
+    /// interact. Defaults to `true` when the environment is `production`.
+    pub fn production_environment(mut self, production_environment: impl Into<bool>) -> Self {
+        self.production_environment = Some(production_environment.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<CreateDeploymentResponse> {
+        #[derive(serde::Deserialize)]
+        struct AutoMerged {
+            message: String,
+        }
+
+        let route = format!("/{}/deployments", self.handler.handler.repo);
+        let response = self.handler.handler.crab._post(route, Some(&self)).await?;
+        let response = crate::map_github_error(response).await?;
+
+        if response.status() == http::StatusCode::ACCEPTED {
+            let AutoMerged { message } = AutoMerged::from_response(response).await?;
+            Ok(CreateDeploymentResponse::AutoMerged { message })
+        } else {
+            Deployment::from_response(response)
+                .await
+                .map(|deployment| CreateDeploymentResponse::Created(Box::new(deployment)))
+        }
+    }
+}
+
+/// A builder pattern struct for listing the statuses of a deployment.
+///
+/// created by [`DeploymentsHandler::list_statuses`]
+#[derive(serde::Serialize)]
+pub struct ListDeploymentStatusesBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(skip)]
+    id: DeploymentId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r1, 'r2> ListDeploymentStatusesBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(handler: &'r2 DeploymentsHandler<'octo, 'r1>, id: DeploymentId) -> Self {
+        Self {
+            handler,
+            id,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<DeploymentStatus>> {
+        let route = format!(
+            "/{}/deployments/{id}/statuses",
+            self.handler.handler.repo,
+            id = self.id
+        );
+        self.handler.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+crate::page::impl_paginate! {
+    ListDeploymentStatusesBuilder<'_, '_, '_>,
+    DeploymentStatus,
+    |b| b.handler.handler.crab
+}
+
+/// A builder pattern struct for creating the status of a deployment.
+///
+/// created by [`DeploymentsHandler::create_status`]
+#[derive(serde::Serialize)]
+pub struct CreateDeploymentStatusBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(skip)]
+    id: DeploymentId,
+    state: DeploymentState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    log_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_inactive: Option<bool>,
+}
+
+impl<'octo, 'r1, 'r2> CreateDeploymentStatusBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(
+        handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+        id: DeploymentId,
+        state: DeploymentState,
+    ) -> Self {
+        Self {
+            handler,
+            id,
+            state,
+            target_url: None,
+            log_url: None,
+            description: None,
+            environment: None,
+            environment_url: None,
+            auto_inactive: None,
+        }
+    }
+
+    /// The URL of the output of the deployment. Prefer
+    /// [`log_url`](Self::log_url), which replaces it.
+    pub fn target_url(mut self, target_url: impl Into<String>) -> Self {
+        self.target_url = Some(target_url.into());
+        self
+    }
+
+    /// The URL of the output of the deployment.
+    pub fn log_url(mut self, log_url: impl Into<String>) -> Self {
+        self.log_url = Some(log_url.into());
+        self
+    }
+
+    /// A short description of the status.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The name of the environment which was deployed to, if it changed.
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// The URL for accessing the environment.
+    pub fn environment_url(mut self, environment_url: impl Into<String>) -> Self {
+        self.environment_url = Some(environment_url.into());
+        self
+    }
+
+    /// Whether to mark the previous non-transient, non-production deployments
+    /// to the same environment as `inactive` on success. Defaults to `true`.
+    pub fn auto_inactive(mut self, auto_inactive: impl Into<bool>) -> Self {
+        self.auto_inactive = Some(auto_inactive.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<DeploymentStatus> {
+        let route = format!(
+            "/{}/deployments/{id}/statuses",
+            self.handler.handler.repo,
+            id = self.id
+        );
+        self.handler.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for reviewing a custom deployment protection
+/// rule.
+///
+/// created by [`DeploymentsHandler::review_protection_rule`]
+#[derive(serde::Serialize)]
+pub struct ReviewProtectionRuleBuilder<'octo, 'r1, 'r2> {
+    #[serde(skip)]
+    handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+    #[serde(skip)]
+    run_id: RunId,
+    environment_name: String,
+    state: ProtectionRuleReviewState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+impl<'octo, 'r1, 'r2> ReviewProtectionRuleBuilder<'octo, 'r1, 'r2> {
+    pub(crate) fn new(
+        handler: &'r2 DeploymentsHandler<'octo, 'r1>,
+        run_id: RunId,
+        environment_name: String,
+        state: ProtectionRuleReviewState,
+    ) -> Self {
+        Self {
+            handler,
+            run_id,
+            environment_name,
+            state,
+            comment: None,
+        }
+    }
+
+    /// A comment explaining the review.
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<()> {
+        let route = format!(
+            "/{}/actions/runs/{run_id}/deployment_protection_rule",
+            self.handler.handler.repo,
+            run_id = self.run_id
+        );
+        crate::map_github_error(self.handler.handler.crab._post(route, Some(&self)).await?)
+            .await
+            .map(drop)
+    }
+}
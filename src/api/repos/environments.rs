@@ -0,0 +1,414 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use super::*;
+use crate::models::repos::environments::{
+    DeploymentBranchPolicy, DeploymentBranchPolicySettings, DeploymentBranchPolicyType,
+    Environment, RequiredReviewer,
+};
+use crate::models::DeploymentBranchPolicyId;
+
+mod secrets;
+mod variables;
+
+pub use secrets::EnvironmentSecretsHandler;
+pub use variables::EnvironmentVariablesHandler;
+
+/// A client to GitHub's repository environments API.
+///
+/// Created with [`RepoHandler::environments`].
+pub struct RepoEnvironmentsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoEnvironmentsHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Creates a new [`ListEnvironmentsBuilder`] to list the environments of
+    /// the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let page = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListEnvironmentsBuilder<'_, 'octo> {
+        ListEnvironmentsBuilder::new(self)
+    }
+
+    /// Gets an environment using its name.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let environment = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .get("production")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, environment_name: impl AsRef<str>) -> Result<Environment> {
+        let route = self.route(environment_name.as_ref());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a new [`CreateOrUpdateEnvironmentBuilder`] to create the
+    /// environment `environment_name`, or update its protection rules if it
+    /// exists.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::environments::{
+    ///     DeploymentBranchPolicySettings, RequiredReviewer,
+    /// };
+    ///
+    /// let environment = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .create_or_update("production")
+    ///     // Optional Parameters
+    ///     .wait_timer(30u32)
+    ///     .prevent_self_review(true)
+    ///     .reviewers(vec![RequiredReviewer::Team { id: 42u64.into() }])
+    ///     .deployment_branch_policy(DeploymentBranchPolicySettings {
+    ///         protected_branches: false,
+    ///         custom_branch_policies: true,
+    ///     })
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_or_update(
+        &self,
+        environment_name: impl AsRef<str>,
+    ) -> CreateOrUpdateEnvironmentBuilder<'_, 'octo> {
+        CreateOrUpdateEnvironmentBuilder::new(self, self.route(environment_name.as_ref()))
+    }
+
+    /// Deletes an environment using its name.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .delete("staging")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, environment_name: impl AsRef<str>) -> Result<()> {
+        let route = self.route(environment_name.as_ref());
+        let resp = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await?;
+        Ok(())
+    }
+
+    /// Creates a new [`ListDeploymentBranchPoliciesBuilder`] to list the
+    /// custom deployment branch policies of an environment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let page = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .list_branch_policies("production")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_branch_policies(
+        &self,
+        environment_name: impl AsRef<str>,
+    ) -> ListDeploymentBranchPoliciesBuilder<'_, 'octo> {
+        ListDeploymentBranchPoliciesBuilder::new(self, self.route(environment_name.as_ref()))
+    }
+
+    /// Creates a custom deployment branch policy of an environment, letting
+    /// the branches, or tags with `Some(DeploymentBranchPolicyType::Tag)`,
+    /// matching the `name` pattern deploy to it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let policy = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .create_branch_policy("production", "release/*", None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_branch_policy(
+        &self,
+        environment_name: impl AsRef<str>,
+        name: impl AsRef<str>,
+        kind: Option<DeploymentBranchPolicyType>,
+    ) -> Result<DeploymentBranchPolicy> {
+        let route = format!(
+            "{}/deployment-branch-policies",
+            self.route(environment_name.as_ref())
+        );
+
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            name: &'a str,
+            #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+            kind: Option<DeploymentBranchPolicyType>,
+        }
+
+        let body = Body {
+            name: name.as_ref(),
+            kind,
+        };
+        self.handler.crab.post(route, Some(&body)).await
+    }
+
+    /// Updates the name pattern of a custom deployment branch policy.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let policy = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .update_branch_policy("production", 1u64.into(), "releases/*")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_branch_policy(
+        &self,
+        environment_name: impl AsRef<str>,
+        branch_policy_id: DeploymentBranchPolicyId,
+        name: impl AsRef<str>,
+    ) -> Result<DeploymentBranchPolicy> {
+        let route = format!(
+            "{}/deployment-branch-policies/{branch_policy_id}",
+            self.route(environment_name.as_ref())
+        );
+        let body = serde_json::json!({ "name": name.as_ref() });
+        self.handler.crab.put(route, Some(&body)).await
+    }
+
+    /// Deletes a custom deployment branch policy.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .delete_branch_policy("production", 1u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_branch_policy(
+        &self,
+        environment_name: impl AsRef<str>,
+        branch_policy_id: DeploymentBranchPolicyId,
+    ) -> Result<()> {
+        let route = format!(
+            "{}/deployment-branch-policies/{branch_policy_id}",
+            self.route(environment_name.as_ref())
+        );
+        let resp = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await?;
+        Ok(())
+    }
+
+    /// Creates an [`EnvironmentSecretsHandler`] for the secrets of an
+    /// environment.
+    pub fn secrets(&self, environment_name: impl AsRef<str>) -> EnvironmentSecretsHandler<'octo> {
+        EnvironmentSecretsHandler::new(self.handler, self.route(environment_name.as_ref()))
+    }
+
+    /// Creates an [`EnvironmentVariablesHandler`] for the variables of an
+    /// environment.
+    pub fn variables(
+        &self,
+        environment_name: impl AsRef<str>,
+    ) -> EnvironmentVariablesHandler<'octo> {
+        EnvironmentVariablesHandler::new(self.handler, self.route(environment_name.as_ref()))
+    }
+
+    fn route(&self, environment_name: &str) -> String {
+        format!(
+            "/{}/environments/{}",
+            self.handler.repo,
+            utf8_percent_encode(environment_name, NON_ALPHANUMERIC)
+        )
+    }
+}
+
+/// A builder pattern struct for listing environments.
+///
+/// created by [`RepoEnvironmentsHandler::list`]
+#[derive(serde::Serialize)]
+pub struct ListEnvironmentsBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r RepoEnvironmentsHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'r, 'octo> ListEnvironmentsBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r RepoEnvironmentsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<Environment>> {
+        let route = format!("/{}/environments", self.handler.handler.repo);
+        self.handler.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+crate::page::impl_paginate! {
+    ListEnvironmentsBuilder<'_, '_>,
+    Environment,
+    |b| b.handler.handler.crab
+}
+
+/// A builder pattern struct for creating or updating an environment.
+///
+/// created by [`RepoEnvironmentsHandler::create_or_update`]
+#[derive(serde::Serialize)]
+pub struct CreateOrUpdateEnvironmentBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r RepoEnvironmentsHandler<'octo>,
+    #[serde(skip)]
+    route: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_timer: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prevent_self_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reviewers: Option<Vec<RequiredReviewer>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deployment_branch_policy: Option<Option<DeploymentBranchPolicySettings>>,
+}
+
+impl<'r, 'octo> CreateOrUpdateEnvironmentBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r RepoEnvironmentsHandler<'octo>, route: String) -> Self {
+        Self {
+            handler,
+            route,
+            wait_timer: None,
+            prevent_self_review: None,
+            reviewers: None,
+            deployment_branch_policy: None,
+        }
+    }
+
+    /// The minutes to wait before deploying to the environment (max 43,200).
+    pub fn wait_timer(mut self, wait_timer: impl Into<u32>) -> Self {
+        self.wait_timer = Some(wait_timer.into());
+        self
+    }
+
+    /// Whether the user who triggered a deployment is prevented from
+    /// approving it.
+    pub fn prevent_self_review(mut self, prevent_self_review: impl Into<bool>) -> Self {
+        self.prevent_self_review = Some(prevent_self_review.into());
+        self
+    }
+
+    /// The users or teams which must review the deployments (max 6).
+    pub fn reviewers(mut self, reviewers: impl Into<Vec<RequiredReviewer>>) -> Self {
+        self.reviewers = Some(reviewers.into());
+        self
+    }
+
+    /// Restricts the branches which can deploy to the environment.
+    pub fn deployment_branch_policy(mut self, policy: DeploymentBranchPolicySettings) -> Self {
+        self.deployment_branch_policy = Some(Some(policy));
+        self
+    }
+
+    /// Lets all branches deploy to the environment.
+    pub fn all_branches(mut self) -> Self {
+        self.deployment_branch_policy = Some(None);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Environment> {
+        self.handler
+            .handler
+            .crab
+            .put(&self.route, Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for listing the custom deployment branch policies
+/// of an environment.
+///
+/// created by [`RepoEnvironmentsHandler::list_branch_policies`]
+#[derive(serde::Serialize)]
+pub struct ListDeploymentBranchPoliciesBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r RepoEnvironmentsHandler<'octo>,
+    #[serde(skip)]
+    route: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'r, 'octo> ListDeploymentBranchPoliciesBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r RepoEnvironmentsHandler<'octo>, route: String) -> Self {
+        Self {
+            handler,
+            route,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<DeploymentBranchPolicy>> {
+        let route = format!("{}/deployment-branch-policies", self.route);
+        self.handler.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+crate::page::impl_paginate! {
+    ListDeploymentBranchPoliciesBuilder<'_, '_>,
+    DeploymentBranchPolicy,
+    |b| b.handler.handler.crab
+}
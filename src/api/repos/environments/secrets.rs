@@ -0,0 +1,166 @@
+use http::StatusCode;
+
+use super::RepoHandler;
+use crate::models::repos::secrets::{
+    CreateRepositorySecret, CreateRepositorySecretResponse, RepositorySecret, RepositorySecrets,
+};
+
+/// A client to GitHub's environment secrets API.
+///
+/// Created with [`RepoEnvironmentsHandler::secrets`](super::RepoEnvironmentsHandler::secrets).
+pub struct EnvironmentSecretsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+    route: String,
+}
+
+impl<'octo> EnvironmentSecretsHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>, route: String) -> Self {
+        Self {
+            handler: repo,
+            route,
+        }
+    }
+
+    /// Lists all secrets available in an environment without revealing their encrypted values.
+    /// Authenticated users must have collaborator access to a repository to create, update, or read secrets.
+    /// OAuth app tokens and personal access tokens (classic) need the `repo` scope to use this endpoint.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let all_secrets = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .get_secrets()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    pub async fn get_secrets(&self) -> crate::Result<RepositorySecrets> {
+        let route = format!("{}/secrets", self.route);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets the public key of an environment, which you need to encrypt secrets.
+    /// You need to encrypt a secret before you can create or update secrets.
+    /// Anyone with read access to the repository can use this endpoint.
+    /// If the repository is private, OAuth app tokens and personal access tokens (classic) need the `repo` scope.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let public_key = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .get_public_key()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
+        let route = format!("{}/secrets/public-key", self.route);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets a single environment secret without revealing its encrypted value.
+    /// Authenticated users must have collaborator access to a repository to create, update, or read secrets.
+    /// OAuth app tokens and personal access tokens (classic) need the `repo` scope to use this endpoint.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let secret_info = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .get_secret("TOKEN")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    pub async fn get_secret(
+        &self,
+        secret_name: impl AsRef<str>,
+    ) -> crate::Result<RepositorySecret> {
+        let route = format!(
+            "{}/secrets/{secret_name}",
+            self.route,
+            secret_name = secret_name.as_ref()
+        );
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates or updates an environment secret with an encrypted value.
+    /// Encrypt your secret with the key of [`get_public_key`](Self::get_public_key),
+    /// using [`crypto_box`](https://crates.io/crates/crypto_box).
+    /// Authenticated users must have collaborator access to a repository to create, update, or read secrets.
+    /// OAuth app tokens and personal access tokens (classic) need the `repo` scope to use this endpoint.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::repos::secrets::{CreateRepositorySecret, CreateRepositorySecretResponse};
+    ///
+    /// let result = octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .create_or_update_secret("GH_TOKEN", &CreateRepositorySecret{
+    ///         key_id: "123456",
+    ///         encrypted_value: "some-b64-encrypted-string",
+    ///     })
+    ///     .await?;
+    ///
+    /// match result {
+    ///    CreateRepositorySecretResponse::Created => println!("Created secret!"),
+    ///    CreateRepositorySecretResponse::Updated => println!("Updated secret!"),
+    /// }
+    /// # Ok(())
+    /// # }
+    pub async fn create_or_update_secret(
+        &self,
+        secret_name: impl AsRef<str>,
+        secret: &CreateRepositorySecret<'_>,
+    ) -> crate::Result<CreateRepositorySecretResponse> {
+        let route = format!(
+            "{}/secrets/{secret_name}",
+            self.route,
+            secret_name = secret_name.as_ref()
+        );
+
+        let resp = {
+            let resp = self.handler.crab._put(route, Some(secret)).await?;
+            crate::map_github_error(resp).await?
+        };
+
+        match resp.status() {
+            StatusCode::CREATED => Ok(CreateRepositorySecretResponse::Created),
+            StatusCode::NO_CONTENT => Ok(CreateRepositorySecretResponse::Updated),
+            status_code => Err(crate::Error::Other {
+                source: format!(
+                    "Unexpected status code from request: {}",
+                    status_code.as_str()
+                )
+                .into(),
+                backtrace: snafu::Backtrace::capture(),
+            }),
+        }
+    }
+
+    /// Deletes a secret in an environment using the secret name.
+    /// Authenticated users must have collaborator access to a repository to create, update, or read secrets.
+    /// OAuth app tokens and personal access tokens (classic) need the `repo` scope to use this endpoint.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .environments()
+    ///     .secrets("production")
+    ///     .delete_secret("GH_TOKEN")
+    ///     .await?;
+    ///
+    /// # Ok(())
+    /// # }
+    pub async fn delete_secret(&self, secret_name: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "{}/secrets/{secret_name}",
+            self.route,
+            secret_name = secret_name.as_ref()
+        );
+
+        let resp = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await?;
+        Ok(())
+    }
+}
@@ -47,6 +47,12 @@ impl<'octo, 'r> ListForksBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListForksBuilder<'_, '_>,
+    crate::models::Repository,
+    |b| b.handler.crab
+}
 #[derive(serde::Serialize)]
 pub struct CreateForkBuilder<'octo, 'r> {
     #[serde(skip)]
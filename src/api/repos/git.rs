@@ -0,0 +1,201 @@
+use super::*;
+use crate::models::repos::git::{FileMode, Tree, TreeEntryType, TreeEntryUpdate};
+
+/// A builder pattern struct for creating trees.
+///
+/// created by [`RepoHandler::create_tree`]
+#[derive(serde::Serialize)]
+pub struct CreateTreeBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    tree: Vec<TreeEntryUpdate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_tree: Option<String>,
+}
+
+impl<'octo, 'r> CreateTreeBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, tree: Vec<TreeEntryUpdate>) -> Self {
+        Self {
+            handler,
+            tree,
+            base_tree: None,
+        }
+    }
+
+    /// The SHA of the tree to update with the entries. Without a base tree,
+    /// the new tree only contains the entries.
+    pub fn base_tree(mut self, base_tree: impl Into<String>) -> Self {
+        self.base_tree = Some(base_tree.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Tree> {
+        let route = format!("/{}/git/trees", self.handler.repo);
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for getting trees.
+///
+/// created by [`RepoHandler::get_tree`]
+#[derive(serde::Serialize)]
+pub struct GetTreeBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    sha: String,
+    // GitHub gets the tree recursively for any value of `recursive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recursive: Option<u8>,
+}
+
+impl<'octo, 'r> GetTreeBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, sha: String) -> Self {
+        Self {
+            handler,
+            sha,
+            recursive: None,
+        }
+    }
+
+    /// Whether to also get the entries of the subtrees. Recursive trees are
+    /// limited to 100,000 entries and 7 MB, see [`Tree::truncated`].
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = if recursive { Some(1) } else { None };
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Tree> {
+        let route = format!("/{}/git/trees/{}", self.handler.repo, self.sha);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+enum Change {
+    Write(String, Vec<u8>),
+    Entry(TreeEntryUpdate),
+}
+
+/// A builder pattern struct for committing changes to files onto a branch.
+///
+/// created by [`RepoHandler::commit_changes`]
+pub struct CommitChangesBuilder<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+    branch: String,
+    message: String,
+    changes: Vec<Change>,
+    author: Option<repos::CommitAuthor>,
+    committer: Option<repos::CommitAuthor>,
+}
+
+impl<'octo, 'r> CommitChangesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, branch: String, message: String) -> Self {
+        Self {
+            handler,
+            branch,
+            message,
+            changes: Vec::new(),
+            author: None,
+            committer: None,
+        }
+    }
+
+    /// Writes `content` to the file at `path`, creating it if it does not
+    /// exist.
+    pub fn write(mut self, path: impl Into<String>, content: impl AsRef<[u8]>) -> Self {
+        self.changes
+            .push(Change::Write(path.into(), content.as_ref().to_owned()));
+        self
+    }
+
+    /// Deletes the file at `path`.
+    pub fn delete(mut self, path: impl Into<String>) -> Self {
+        self.changes
+            .push(Change::Entry(TreeEntryUpdate::delete(path)));
+        self
+    }
+
+    /// Writes a tree entry as is, e.g. an executable file or a symbolic link.
+    pub fn entry(mut self, entry: TreeEntryUpdate) -> Self {
+        self.changes.push(Change::Entry(entry));
+        self
+    }
+
+    /// The author of the commit.
+    pub fn author(mut self, author: impl Into<repos::CommitAuthor>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// The committer of the commit.
+    pub fn committer(mut self, committer: impl Into<repos::CommitAuthor>) -> Self {
+        self.committer = Some(committer.into());
+        self
+    }
+
+    /// Sends the requests, returning the new commit of the branch.
+    ///
+    /// The branch is only updated if it still points to the commit the
+    /// changes were made on, so the changes are either committed at once or
+    /// not at all.
+    pub async fn send(self) -> Result<GitCommitObject> {
+        let reference = params::repos::Reference::Branch(self.branch.clone());
+        let parent = match self.handler.get_ref(&reference).await?.object {
+            repos::Object::Commit { sha, .. } => sha,
+            repos::Object::Tag { .. } => {
+                return Err(crate::Error::Other {
+                    source: format!("Reference `{}` is not a branch", self.branch).into(),
+                    backtrace: snafu::Backtrace::capture(),
+                })
+            }
+        };
+        let parent_commit: GitCommitObject = self
+            .handler
+            .crab
+            .get(
+                format!("/{}/git/commits/{parent}", self.handler.repo),
+                None::<&()>,
+            )
+            .await?;
+
+        let mut entries = Vec::with_capacity(self.changes.len());
+        for change in self.changes {
+            let entry = match change {
+                Change::Write(path, content) => match String::from_utf8(content) {
+                    Ok(content) => TreeEntryUpdate::content(path, FileMode::File, content),
+                    Err(error) => {
+                        let blob = self.handler.create_blob(error.into_bytes()).await?;
+                        TreeEntryUpdate::object(path, FileMode::File, TreeEntryType::Blob, blob.sha)
+                    }
+                },
+                Change::Entry(entry) => entry,
+            };
+            entries.push(entry);
+        }
+        let tree = self
+            .handler
+            .create_tree(entries)
+            .base_tree(parent_commit.tree.sha)
+            .send()
+            .await?;
+
+        let mut commit = self
+            .handler
+            .create_git_commit_object(self.message, tree.sha)
+            .parents(vec![parent]);
+        if let Some(author) = self.author {
+            commit = commit.author(author);
+        }
+        if let Some(committer) = self.committer {
+            commit = commit.committer(committer);
+        }
+        let commit = commit.send().await?;
+
+        self.handler
+            .update_ref(&reference, commit.sha.clone(), false)
+            .await?;
+        Ok(commit)
+    }
+}
@@ -40,3 +40,9 @@ impl<'octo, 'r> ListPullsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListPullsBuilder<'_, '_>,
+    crate::models::pulls::PullRequest,
+    |b| b.handler.crab
+}
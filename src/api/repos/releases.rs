@@ -307,6 +307,12 @@ impl<'octo, 'r1, 'r2> ListReleasesBuilder<'octo, 'r1, 'r2> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListReleasesBuilder<'_, '_, '_>,
+    crate::models::repos::Release,
+    |b| b.handler.handler.crab
+}
+
 /// A builder pattern struct for creating releases.
 ///
 /// created by [`ReleasesHandler::create`].
@@ -328,6 +334,8 @@ pub struct CreateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_comm
     prerelease: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     make_latest: Option<MakeLatest>,
+    #[serde(skip)]
+    retryable: bool,
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize)]
@@ -366,6 +374,7 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
             draft: None,
             prerelease: None,
             make_latest: None,
+            retryable: false,
         }
     }
 
@@ -414,10 +423,22 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
         self
     }
 
+    /// Allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`]. *NOTE: If GitHub processed the first attempt,
+    /// the release is created twice.*
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::models::repos::Release> {
         let route = format!("/{}/releases", self.handler.handler.repo);
-        self.handler.handler.crab.post(route, Some(&self)).await
+        self.handler
+            .handler
+            .crab
+            .send_mutation(http::Method::POST, route, Some(&self), self.retryable)
+            .await
     }
 }
 
@@ -444,6 +465,8 @@ pub struct UpdateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_comm
     prerelease: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     make_latest: Option<MakeLatest>,
+    #[serde(skip)]
+    retryable: bool,
 }
 
 impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
@@ -460,6 +483,7 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
             draft: None,
             prerelease: None,
             make_latest: None,
+            retryable: false,
         }
     }
 
@@ -513,6 +537,13 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
         self
     }
 
+    /// Allows retrying the request after a server error or a timeout, see
+    /// [`crate::Retryable`].
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::models::repos::Release> {
         let route = format!(
@@ -520,7 +551,11 @@ impl<'octo, 'repos, 'handler, 'tag_name, 'target_commitish, 'name, 'body>
             repo = self.handler.handler.repo,
             release_id = self.release_id,
         );
-        self.handler.handler.crab.patch(route, Some(&self)).await
+        self.handler
+            .handler
+            .crab
+            .send_mutation(http::Method::PATCH, route, Some(&self), self.retryable)
+            .await
     }
 }
 
@@ -668,6 +703,12 @@ impl<'octo, 'r1, 'r2> ListReleaseAssetsBuilder<'octo, 'r1, 'r2> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListReleaseAssetsBuilder<'_, '_, '_>,
+    crate::models::repos::Asset,
+    |b| b.handler.handler.crab
+}
+
 /// A builder pattern struct for updating release assets.
 ///
 /// created by [`ReleasesHandler::upload_asset`].
@@ -44,3 +44,9 @@ impl<'octo, 'r> ListStarGazersBuilder<'octo, 'r> {
             .await
     }
 }
+
+crate::page::impl_paginate! {
+    ListStarGazersBuilder<'_, '_>,
+    crate::models::StarGazer,
+    |b| b.handler.crab
+}
@@ -111,3 +111,9 @@ impl<'octo, 'r> ListStatusesBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListStatusesBuilder<'_, '_>,
+    crate::models::Status,
+    |b| b.handler.crab
+}
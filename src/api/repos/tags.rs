@@ -37,3 +37,9 @@ impl<'octo, 'r> ListTagsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListTagsBuilder<'_, '_>,
+    crate::models::repos::Tag,
+    |b| b.handler.crab
+}
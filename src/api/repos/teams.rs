@@ -37,3 +37,9 @@ impl<'octo, 'r> ListTeamsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListTeamsBuilder<'_, '_>,
+    crate::models::teams::Team,
+    |b| b.handler.crab
+}
@@ -0,0 +1,533 @@
+//! The rulesets API.
+use crate::models::rulesets::{
+    BranchRule, BypassActor, Rule, RuleSuite, Ruleset, RulesetConditions, RulesetEnforcement,
+    RulesetTarget,
+};
+use crate::models::{RuleSuiteId, RulesetId};
+use crate::{params, Octocrab, Result};
+
+/// A client to GitHub's rulesets API, for the rulesets of either a repository
+/// or an organization.
+///
+/// Created with [`RepoHandler::rulesets`](crate::repos::RepoHandler::rulesets)
+/// or [`OrgHandler::rulesets`](crate::orgs::OrgHandler::rulesets).
+pub struct RulesetsHandler<'octo> {
+    crab: &'octo Octocrab,
+    route: String,
+}
+
+impl<'octo> RulesetsHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String) -> Self {
+        Self { crab, route }
+    }
+
+    /// Creates a new [`ListRulesetsBuilder`] to list the rulesets.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::rulesets::RulesetTarget;
+    ///
+    /// let page = octocrab.repos("owner", "repo")
+    ///     .rulesets()
+    ///     .list()
+    ///     // Optional Parameters
+    ///     .targets(vec![RulesetTarget::Branch, RulesetTarget::Tag])
+    ///     .includes_parents(false)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListRulesetsBuilder<'_, 'octo> {
+        ListRulesetsBuilder::new(self)
+    }
+
+    /// Gets a ruleset with its rules.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let ruleset = octocrab.orgs("org")
+    ///     .rulesets()
+    ///     .get(42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, ruleset_id: RulesetId) -> Result<Ruleset> {
+        let route = format!("{}/rulesets/{ruleset_id}", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a new [`CreateRulesetBuilder`] to create a ruleset.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::rulesets::{
+    ///     RefNameCondition, Rule, RulesetConditions, RulesetEnforcement, RulesetTarget,
+    /// };
+    ///
+    /// let ruleset = octocrab.repos("owner", "repo")
+    ///     .rulesets()
+    ///     .create("protect main", RulesetEnforcement::Active)
+    ///     // Optional Parameters
+    ///     .target(RulesetTarget::Branch)
+    ///     .conditions(RulesetConditions::default().ref_name(RefNameCondition {
+    ///         include: vec!["~DEFAULT_BRANCH".to_string()],
+    ///         exclude: vec![],
+    ///     }))
+    ///     .rules(vec![Rule::Deletion, Rule::NonFastForward])
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(
+        &self,
+        name: impl Into<String>,
+        enforcement: RulesetEnforcement,
+    ) -> CreateRulesetBuilder<'_, 'octo> {
+        CreateRulesetBuilder::new(self, name.into(), enforcement)
+    }
+
+    /// Creates a new [`UpdateRulesetBuilder`] to update a ruleset. The fields
+    /// which are set replace the ones of the ruleset.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::rulesets::RulesetEnforcement;
+    ///
+    /// let ruleset = octocrab.orgs("org")
+    ///     .rulesets()
+    ///     .update(42u64.into())
+    ///     .enforcement(RulesetEnforcement::Disabled)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, ruleset_id: RulesetId) -> UpdateRulesetBuilder<'_, 'octo> {
+        UpdateRulesetBuilder::new(self, ruleset_id)
+    }
+
+    /// Deletes a ruleset.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// octocrab.repos("owner", "repo")
+    ///     .rulesets()
+    ///     .delete(42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, ruleset_id: RulesetId) -> Result<()> {
+        let route = format!("{}/rulesets/{ruleset_id}", self.route);
+        let resp = self.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await?;
+        Ok(())
+    }
+
+    /// Creates a new [`ListRuleSuitesBuilder`] to list the evaluations of the
+    /// rules for recent pushes.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::params::rulesets::{RuleSuiteResult, TimePeriod};
+    ///
+    /// let page = octocrab.orgs("org")
+    ///     .rulesets()
+    ///     .list_rule_suites()
+    ///     // Optional Parameters
+    ///     .repository_name("repo")
+    ///     .time_period(TimePeriod::Week)
+    ///     .rule_suite_result(RuleSuiteResult::Fail)
+    ///     // Send the request
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_rule_suites(&self) -> ListRuleSuitesBuilder<'_, 'octo> {
+        ListRuleSuitesBuilder::new(self)
+    }
+
+    /// Gets a rule suite with the evaluation of each rule.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let rule_suite = octocrab.repos("owner", "repo")
+    ///     .rulesets()
+    ///     .get_rule_suite(42u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_rule_suite(&self, rule_suite_id: RuleSuiteId) -> Result<RuleSuite> {
+        let route = format!("{}/rulesets/rule-suites/{rule_suite_id}", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+}
+
+/// A builder pattern struct for listing rulesets.
+///
+/// created by [`RulesetsHandler::list`]
+#[derive(serde::Serialize)]
+pub struct ListRulesetsBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r RulesetsHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "comma_separated")]
+    targets: Option<Vec<RulesetTarget>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    includes_parents: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'r, 'octo> ListRulesetsBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r RulesetsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            targets: None,
+            includes_parents: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list the rulesets with these targets.
+    pub fn targets(mut self, targets: impl Into<Vec<RulesetTarget>>) -> Self {
+        self.targets = Some(targets.into());
+        self
+    }
+
+    /// Whether to also list the rulesets of the organization, for the
+    /// rulesets of a repository. Defaults to `true`.
+    pub fn includes_parents(mut self, includes_parents: impl Into<bool>) -> Self {
+        self.includes_parents = Some(includes_parents.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Page<Ruleset>> {
+        let route = format!("{}/rulesets", self.handler.route);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+crate::page::impl_paginate! { ListRulesetsBuilder<'_, '_>, Ruleset, |b| b.handler.crab }
+
+/// A builder pattern struct for creating rulesets.
+///
+/// created by [`RulesetsHandler::create`]
+#[derive(serde::Serialize)]
+pub struct CreateRulesetBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r RulesetsHandler<'octo>,
+    name: String,
+    enforcement: RulesetEnforcement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<RulesetTarget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bypass_actors: Option<Vec<BypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conditions: Option<RulesetConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rules: Option<Vec<Rule>>,
+}
+
+impl<'r, 'octo> CreateRulesetBuilder<'r, 'octo> {
+    pub(crate) fn new(
+        handler: &'r RulesetsHandler<'octo>,
+        name: String,
+        enforcement: RulesetEnforcement,
+    ) -> Self {
+        Self {
+            handler,
+            name,
+            enforcement,
+            target: None,
+            bypass_actors: None,
+            conditions: None,
+            rules: None,
+        }
+    }
+
+    /// What the ruleset applies to. Defaults to `branch`.
+    pub fn target(mut self, target: RulesetTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// The actors which can bypass the rules.
+    pub fn bypass_actors(mut self, bypass_actors: impl Into<Vec<BypassActor>>) -> Self {
+        self.bypass_actors = Some(bypass_actors.into());
+        self
+    }
+
+    /// The refs and repositories the ruleset applies to.
+    pub fn conditions(mut self, conditions: RulesetConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    /// The rules to enforce.
+    pub fn rules(mut self, rules: impl Into<Vec<Rule>>) -> Self {
+        self.rules = Some(rules.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Ruleset> {
+        let route = format!("{}/rulesets", self.handler.route);
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for updating rulesets.
+///
+/// created by [`RulesetsHandler::update`]
+#[derive(serde::Serialize)]
+pub struct UpdateRulesetBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r RulesetsHandler<'octo>,
+    #[serde(skip)]
+    ruleset_id: RulesetId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enforcement: Option<RulesetEnforcement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<RulesetTarget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bypass_actors: Option<Vec<BypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conditions: Option<RulesetConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rules: Option<Vec<Rule>>,
+}
+
+impl<'r, 'octo> UpdateRulesetBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r RulesetsHandler<'octo>, ruleset_id: RulesetId) -> Self {
+        Self {
+            handler,
+            ruleset_id,
+            name: None,
+            enforcement: None,
+            target: None,
+            bypass_actors: None,
+            conditions: None,
+            rules: None,
+        }
+    }
+
+    /// The name of the ruleset.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the rules are enforced.
+    pub fn enforcement(mut self, enforcement: RulesetEnforcement) -> Self {
+        self.enforcement = Some(enforcement);
+        self
+    }
+
+    /// What the ruleset applies to.
+    pub fn target(mut self, target: RulesetTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// The actors which can bypass the rules.
+    pub fn bypass_actors(mut self, bypass_actors: impl Into<Vec<BypassActor>>) -> Self {
+        self.bypass_actors = Some(bypass_actors.into());
+        self
+    }
+
+    /// The refs and repositories the ruleset applies to.
+    pub fn conditions(mut self, conditions: RulesetConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    /// The rules to enforce.
+    pub fn rules(mut self, rules: impl Into<Vec<Rule>>) -> Self {
+        self.rules = Some(rules.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Ruleset> {
+        let route = format!("{}/rulesets/{}", self.handler.route, self.ruleset_id);
+        self.handler.crab.put(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for listing rule suites.
+///
+/// created by [`RulesetsHandler::list_rule_suites`]
+#[derive(serde::Serialize)]
+pub struct ListRuleSuitesBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r RulesetsHandler<'octo>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_period: Option<params::rulesets::TimePeriod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actor_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_suite_result: Option<params::rulesets::RuleSuiteResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'r, 'octo> ListRuleSuitesBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r RulesetsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            reference: None,
+            repository_name: None,
+            time_period: None,
+            actor_name: None,
+            rule_suite_result: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list the rule suites of pushes to `reference`, e.g.
+    /// `refs/heads/main`.
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Only list the rule suites of pushes to the repository, for the rule
+    /// suites of an organization.
+    pub fn repository_name(mut self, repository_name: impl Into<String>) -> Self {
+        self.repository_name = Some(repository_name.into());
+        self
+    }
+
+    /// Only list the rule suites of this period. Defaults to `day`.
+    pub fn time_period(mut self, time_period: params::rulesets::TimePeriod) -> Self {
+        self.time_period = Some(time_period);
+        self
+    }
+
+    /// Only list the rule suites of pushes by the actor.
+    pub fn actor_name(mut self, actor_name: impl Into<String>) -> Self {
+        self.actor_name = Some(actor_name.into());
+        self
+    }
+
+    /// Only list the rule suites with this result. Defaults to `all`.
+    pub fn rule_suite_result(mut self, result: params::rulesets::RuleSuiteResult) -> Self {
+        self.rule_suite_result = Some(result);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Page<RuleSuite>> {
+        let route = format!("{}/rulesets/rule-suites", self.handler.route);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+crate::page::impl_paginate! { ListRuleSuitesBuilder<'_, '_>, RuleSuite, |b| b.handler.crab }
+
+/// A builder pattern struct for listing the rules applying to a branch.
+///
+/// created by [`RepoHandler::branch_rules`](crate::repos::RepoHandler::branch_rules)
+#[derive(serde::Serialize)]
+pub struct ListBranchRulesBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip)]
+    route: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo> ListBranchRulesBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String) -> Self {
+        Self {
+            crab,
+            route,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Page<BranchRule>> {
+        self.crab.get(&self.route, Some(&self)).await
+    }
+}
+
+crate::page::impl_paginate! { ListBranchRulesBuilder<'_>, BranchRule, |b| b.crab }
+
+fn comma_separated<S: serde::Serializer>(
+    targets: &Option<Vec<RulesetTarget>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let targets = targets
+        .iter()
+        .flatten()
+        .map(|target| match target {
+            RulesetTarget::Branch => "branch",
+            RulesetTarget::Tag => "tag",
+            RulesetTarget::Push => "push",
+            RulesetTarget::Repository => "repository",
+        })
+        .collect::<Vec<_>>();
+    serializer.serialize_str(&targets.join(","))
+}
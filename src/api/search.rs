@@ -183,3 +183,9 @@ impl<T: serde::de::DeserializeOwned> QueryHandler<'_, '_, T> {
             .await
     }
 }
+
+crate::page::impl_paginate! {
+    [T: serde::de::DeserializeOwned + Send + Sync + 'static] QueryHandler<'_, '_, T>,
+    T,
+    |b| b.crab
+}
@@ -45,3 +45,9 @@ impl<'octo, 'r> ListChildTeamsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListChildTeamsBuilder<'_, '_>,
+    models::teams::RequestedTeam,
+    |b| b.handler.crab
+}
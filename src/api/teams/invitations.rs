@@ -45,3 +45,9 @@ impl<'octo, 'r> ListTeamInvitationsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListTeamInvitationsBuilder<'_, '_>,
+    teams::TeamInvitation,
+    |b| b.handler.crab
+}
@@ -38,3 +38,9 @@ impl<'octo, 'r> ListTeamsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListTeamsBuilder<'_, '_>,
+    models::teams::RequestedTeam,
+    |b| b.handler.crab
+}
@@ -45,3 +45,5 @@ impl<'octo, 'r> ListTeamMembersBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! { ListTeamMembersBuilder<'_, '_>, models::Author, |b| b.handler.crab }
@@ -43,6 +43,12 @@ impl<'octo, 'b> ListUserFollowerBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListUserFollowerBuilder<'_, '_>,
+    crate::models::Follower,
+    |b| b.handler.crab
+}
+
 /// A builder pattern struct for listing who a user is following
 ///
 /// created by [`UserHandler::following`]
@@ -84,3 +90,9 @@ impl<'octo, 'b> ListUserFollowingBuilder<'octo, 'b> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+crate::page::impl_paginate! {
+    ListUserFollowingBuilder<'_, '_>,
+    crate::models::Followee,
+    |b| b.handler.crab
+}
@@ -71,6 +71,12 @@ impl<'octo, 'b> ListUserReposBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListUserReposBuilder<'_, '_>,
+    crate::models::Repository,
+    |b| b.handler.crab
+}
+
 #[cfg(test)]
 mod tests {
     #[tokio::test]
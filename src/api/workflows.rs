@@ -163,6 +163,12 @@ impl<'octo, 'b> ListWorkflowsBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! {
+    ListWorkflowsBuilder<'_, '_>,
+    models::workflows::WorkFlow,
+    |b| b.handler.crab
+}
+
 /// The type of list workflow runs request.
 pub(crate) enum ListRunsRequestType {
     ByRepo,
@@ -287,6 +293,8 @@ impl<'octo, 'b> ListRunsBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! { ListRunsBuilder<'_, '_>, models::workflows::Run, |b| b.handler.crab }
+
 #[derive(serde::Serialize)]
 pub struct ListJobsBuilder<'octo, 'b> {
     #[serde(skip)]
@@ -342,6 +350,8 @@ impl<'octo, 'b> ListJobsBuilder<'octo, 'b> {
     }
 }
 
+crate::page::impl_paginate! { ListJobsBuilder<'_, '_>, models::workflows::Job, |b| b.handler.crab }
+
 #[cfg(test)]
 mod tests {
     #[tokio::test]
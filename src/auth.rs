@@ -1,11 +1,19 @@
 //! Authentication related types and functions.
 
+use crate::service::middleware::cache::CacheIdentity;
 use crate::Result;
-use crate::{models::AppId, Octocrab};
+use crate::{
+    models::{AppId, RepositoryId},
+    Octocrab,
+};
+use chrono::{DateTime, Utc};
+use http::{HeaderValue, Uri};
 use jsonwebtoken::{Algorithm, EncodingKey, Header};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
+use std::sync::{Arc, RwLock};
 #[cfg(feature = "tokio")]
 use web_time::Duration;
 use web_time::SystemTime;
@@ -29,6 +37,55 @@ impl fmt::Debug for AppAuth {
     }
 }
 
+/// Restricts the repositories and permissions of an installation access
+/// token, see [`Octocrab::scoped_installation`].
+///
+/// Repositories may be given by name or by ID. Without any, the token can
+/// access all the repositories of the installation. Without any permission,
+/// the token has all the permissions of the installation.
+///
+/// ```
+/// use octocrab::auth::InstallationTokenScope;
+///
+/// let scope = InstallationTokenScope::new()
+///     .repository("octocrab")
+///     .permission("contents", "read");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct InstallationTokenScope {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    repositories: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    repository_ids: Vec<RepositoryId>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    permissions: BTreeMap<String, String>,
+}
+
+impl InstallationTokenScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the token to the repository named `name`, without its owner.
+    pub fn repository(mut self, name: impl Into<String>) -> Self {
+        self.repositories.push(name.into());
+        self
+    }
+
+    /// Restricts the token to the repository with ID `id`.
+    pub fn repository_id(mut self, id: impl Into<RepositoryId>) -> Self {
+        self.repository_ids.push(id.into());
+        self
+    }
+
+    /// Grants `level` (`read`, `write` or `admin`) access to `permission`,
+    /// e.g. `contents` or `issues`.
+    pub fn permission(mut self, permission: impl Into<String>, level: impl Into<String>) -> Self {
+        self.permissions.insert(permission.into(), level.into());
+        self
+    }
+}
+
 /// The forms of authentication we support
 pub enum Auth {
     /// No authentication
@@ -46,6 +103,10 @@ pub enum Auth {
     App(AppAuth),
     /// Authenticate as a Github OAuth App
     OAuth(OAuth),
+    /// Authenticate with an expiring user access token, refreshed automatically
+    OAuthRefresh(OAuthRefresh),
+    /// Authenticate with credentials supplied by a [`CredentialProvider`]
+    CredentialProvider(Arc<dyn CredentialProvider>),
     /// Authenticate using a User Access Token
     UserAccessToken(SecretString),
 }
@@ -56,6 +117,68 @@ impl Default for Auth {
     }
 }
 
+/// Supplies the credentials of the requests made by [`Octocrab`].
+///
+/// Implement this trait to take credentials from a secrets manager, a token
+/// broker or a pool of rotating tokens, without rebuilding the client. The
+/// built-in authentication modes are implemented by [`crate::AuthState`].
+///
+/// ```no_run
+/// use http::HeaderValue;
+/// use octocrab::{auth::CredentialProvider, Octocrab};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// /// Spreads requests over several personal access tokens.
+/// struct TokenPool {
+///     tokens: Vec<String>,
+///     next: AtomicUsize,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl CredentialProvider for TokenPool {
+///     async fn authorization(&self, _: &Octocrab) -> octocrab::Result<Option<HeaderValue>> {
+///         let next = self.next.fetch_add(1, Ordering::Relaxed) % self.tokens.len();
+///         Ok(HeaderValue::from_str(&format!("Bearer {}", self.tokens[next])).ok())
+///     }
+/// }
+///
+/// # fn run() -> octocrab::Result<()> {
+/// let octocrab = Octocrab::builder()
+///     .credential_provider(TokenPool {
+///         tokens: vec!["ghp_first".into(), "ghp_second".into()],
+///         next: AtomicUsize::new(0),
+///     })
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait CredentialProvider: Send + Sync {
+    /// Returns the value of the `Authorization` header of the next request, or
+    /// `None` to send it anonymously.
+    ///
+    /// `crab` may be used to request tokens from GitHub with
+    /// [`Octocrab::send`]; [`Octocrab::execute`] would call the provider again.
+    async fn authorization(&self, crab: &Octocrab) -> Result<Option<HeaderValue>>;
+
+    /// Called when GitHub rejected the credentials with `401 Unauthorized`,
+    /// e.g. to drop a cached token.
+    fn unauthorized(&self) {}
+
+    /// Returns the identity cached responses and rate limits are tracked
+    /// under. Defaults to a digest of the `Authorization` header, see
+    /// [`CacheIdentity`].
+    fn identity(&self) -> Option<CacheIdentity> {
+        None
+    }
+}
+
+impl fmt::Debug for dyn CredentialProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CredentialProvider")
+    }
+}
+
 /// Create a JSON Web Token that can be used to authenticate an a GitHub application.
 ///
 /// See: <https://docs.github.com/en/developers/apps/getting-started-with-apps/setting-up-your-development-environment-to-create-a-github-app#authenticating-as-a-github-app>
@@ -130,6 +253,240 @@ impl From<OAuthWire> for OAuth {
     }
 }
 
+/// Default endpoint used to refresh user access tokens.
+pub const GITHUB_OAUTH_TOKEN_URI: &str = "https://github.com/login/oauth/access_token";
+
+/// Refresh a token this long before it expires.
+const REFRESH_BUFFER_SECONDS: i64 = 60;
+
+type RefreshCallback = Arc<dyn Fn(&OAuth) + Send + Sync>;
+
+/// An expiring GitHub App user access token, along with what is needed to
+/// refresh it.
+///
+/// The token is refreshed shortly before it expires, or when GitHub answers
+/// with a `401 Unauthorized`. As refresh tokens can only be used once, the
+/// new token pair should be persisted from the [`OAuthRefresh::on_refresh`]
+/// callback.
+///
+/// See <https://docs.github.com/en/apps/creating-github-apps/authenticating-with-a-github-app/refreshing-user-access-tokens>
+///
+/// ```no_run
+/// # fn run(oauth: octocrab::auth::OAuth) -> octocrab::Result<()> {
+/// use octocrab::auth::OAuthRefresh;
+///
+/// let octocrab = octocrab::Octocrab::builder()
+///     .oauth_refresh(
+///         OAuthRefresh::new(oauth, "client id", "client secret".to_string())
+///             .on_refresh(|oauth| println!("new token expires in {:?}s", oauth.expires_in)),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct OAuthRefresh {
+    oauth: OAuth,
+    client_id: String,
+    client_secret: SecretString,
+    token_uri: Uri,
+    on_refresh: Option<RefreshCallback>,
+}
+
+impl OAuthRefresh {
+    pub fn new(
+        oauth: OAuth,
+        client_id: impl Into<String>,
+        client_secret: impl Into<SecretString>,
+    ) -> Self {
+        Self {
+            oauth,
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            token_uri: Uri::from_static(GITHUB_OAUTH_TOKEN_URI),
+            on_refresh: None,
+        }
+    }
+
+    /// Sets the endpoint used to refresh the token, e.g.
+    /// `https://HOSTNAME/login/oauth/access_token` for GitHub Enterprise Server.
+    pub fn token_uri(mut self, token_uri: Uri) -> Self {
+        self.token_uri = token_uri;
+        self
+    }
+
+    /// Sets a callback invoked with the new tokens after each refresh.
+    pub fn on_refresh(mut self, on_refresh: impl Fn(&OAuth) + Send + Sync + 'static) -> Self {
+        self.on_refresh = Some(Arc::new(on_refresh));
+        self
+    }
+}
+
+struct TokenPair {
+    access_token: SecretString,
+    token_type: String,
+    expires_at: Option<DateTime<Utc>>,
+    refresh_token: Option<SecretString>,
+}
+
+impl From<&OAuth> for TokenPair {
+    fn from(oauth: &OAuth) -> Self {
+        Self {
+            access_token: oauth.access_token.clone(),
+            token_type: oauth.token_type.clone(),
+            expires_at: oauth
+                .expires_in
+                .map(|secs| Utc::now() + chrono::Duration::seconds(secs as i64)),
+            refresh_token: oauth.refresh_token.clone(),
+        }
+    }
+}
+
+/// State of an [`OAuthRefresh`] authentication, shared by the clones of an
+/// [`Octocrab`].
+pub struct RefreshingToken {
+    client_id: String,
+    client_secret: SecretString,
+    token_uri: Uri,
+    on_refresh: Option<RefreshCallback>,
+    tokens: RwLock<TokenPair>,
+    // Refresh tokens are single-use, only one refresh may be in flight.
+    refreshing: futures::lock::Mutex<()>,
+}
+
+impl From<OAuthRefresh> for RefreshingToken {
+    fn from(refresh: OAuthRefresh) -> Self {
+        Self {
+            tokens: RwLock::new(TokenPair::from(&refresh.oauth)),
+            client_id: refresh.client_id,
+            client_secret: refresh.client_secret,
+            token_uri: refresh.token_uri,
+            on_refresh: refresh.on_refresh,
+            refreshing: futures::lock::Mutex::new(()),
+        }
+    }
+}
+
+impl RefreshingToken {
+    /// Returns the value of the `Authorization` header, refreshing the token
+    /// first if it is about to expire.
+    pub(crate) async fn authorization(&self, crab: &Octocrab) -> Result<String> {
+        let (access_token, expiring) = {
+            let tokens = self.tokens.read().unwrap();
+            let expiring = tokens.refresh_token.is_some()
+                && tokens.expires_at.is_some_and(|expires_at| {
+                    expires_at - Utc::now() < chrono::Duration::seconds(REFRESH_BUFFER_SECONDS)
+                });
+            (tokens.access_token.clone(), expiring)
+        };
+        if expiring {
+            self.refresh(crab, &access_token).await?;
+        }
+
+        let tokens = self.tokens.read().unwrap();
+        Ok(format!(
+            "{} {}",
+            tokens.token_type,
+            tokens.access_token.expose_secret()
+        ))
+    }
+
+    /// Refreshes the token, unless `stale` was already replaced by a
+    /// concurrent refresh.
+    pub(crate) async fn refresh(&self, crab: &Octocrab, stale: &SecretString) -> Result<()> {
+        let _refreshing = self.refreshing.lock().await;
+
+        let refresh_token = {
+            let tokens = self.tokens.read().unwrap();
+            if tokens.access_token.expose_secret() != stale.expose_secret() {
+                return Ok(());
+            }
+            tokens
+                .refresh_token
+                .clone()
+                .context(crate::error::OAuthRefreshSnafu {
+                    message: "no refresh token available",
+                })?
+        };
+
+        let request = http::Request::builder()
+            .method(http::Method::POST)
+            .uri(self.token_uri.clone())
+            .header(http::header::ACCEPT, "application/json");
+        let request = crab.build_request(
+            request,
+            Some(&RefreshTokenRequest {
+                client_id: &self.client_id,
+                client_secret: self.client_secret.expose_secret(),
+                grant_type: "refresh_token",
+                refresh_token: refresh_token.expose_secret(),
+            }),
+        )?;
+        let response = crate::map_github_error(crab.send(request).await?).await?;
+
+        let oauth =
+            match <RefreshTokenResponse as crate::FromResponse>::from_response(response).await? {
+                RefreshTokenResponse::Ok(oauth) => oauth,
+                RefreshTokenResponse::Err {
+                    error,
+                    error_description,
+                } => {
+                    return crate::error::OAuthRefreshSnafu {
+                        message: error_description.unwrap_or(error),
+                    }
+                    .fail()
+                }
+            };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Refreshed user access token");
+
+        *self.tokens.write().unwrap() = TokenPair::from(&oauth);
+        if let Some(ref on_refresh) = self.on_refresh {
+            on_refresh(&oauth);
+        }
+        Ok(())
+    }
+
+    /// Returns the current access token, if it can be refreshed.
+    pub(crate) fn refreshable_token(&self) -> Option<SecretString> {
+        let tokens = self.tokens.read().unwrap();
+        tokens
+            .refresh_token
+            .as_ref()
+            .map(|_| tokens.access_token.clone())
+    }
+}
+
+impl fmt::Debug for RefreshingToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tokens = self.tokens.read().unwrap();
+        f.debug_struct("RefreshingToken")
+            .field("client_id", &self.client_id)
+            .field("token_uri", &self.token_uri)
+            .field("expires_at", &tokens.expires_at)
+            .finish_non_exhaustive()
+    }
+}
+
+/// See <https://docs.github.com/en/apps/creating-github-apps/authenticating-with-a-github-app/refreshing-user-access-tokens#refreshing-a-user-access-token-with-a-refresh-token>
+#[derive(Serialize)]
+struct RefreshTokenRequest<'a> {
+    client_id: &'a str,
+    client_secret: &'a str,
+    grant_type: &'static str,
+    refresh_token: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RefreshTokenResponse {
+    Ok(OAuth),
+    Err {
+        error: String,
+        error_description: Option<String>,
+    },
+}
+
 impl crate::Octocrab {
     /// Authenticate with Github's device flow. This starts the process to obtain a new `OAuth`.
     ///
@@ -176,6 +533,49 @@ impl crate::Octocrab {
             .await?;
         Ok(codes)
     }
+
+    /// Fetches an OpenID Connect ID token for the running GitHub Actions job,
+    /// e.g. to exchange it for cloud provider credentials. `audience` defaults
+    /// to the URL of the repository owner.
+    ///
+    /// The job needs the `id-token: write` permission, which sets the
+    /// `ACTIONS_ID_TOKEN_REQUEST_URL` and `ACTIONS_ID_TOKEN_REQUEST_TOKEN`
+    /// environment variables.
+    ///
+    /// See <https://docs.github.com/en/actions/security-for-github-actions/security-hardening-your-deployments/about-security-hardening-with-openid-connect>
+    pub async fn actions_id_token(&self, audience: Option<&str>) -> Result<SecretString> {
+        let var = |name: &'static str| {
+            std::env::var(name).context(crate::error::ActionsEnvironmentSnafu { name })
+        };
+        let request_url = var("ACTIONS_ID_TOKEN_REQUEST_URL")?;
+        let request_token = SecretString::from(var("ACTIONS_ID_TOKEN_REQUEST_TOKEN")?);
+
+        let uri = self.parameterized_uri(
+            request_url,
+            audience.map(|audience| [("audience", audience)]).as_ref(),
+        )?;
+        let mut authorization =
+            HeaderValue::from_str(&format!("Bearer {}", request_token.expose_secret()))
+                .map_err(http::Error::from)
+                .context(crate::error::HttpSnafu)?;
+        authorization.set_sensitive(true);
+        let request = http::Request::builder()
+            .method(http::Method::GET)
+            .uri(uri)
+            .header(http::header::AUTHORIZATION, authorization)
+            .header(http::header::ACCEPT, "application/json");
+        // Sent directly, as the token service is not GitHub's API.
+        let request = self.build_request(request, None::<&()>)?;
+        let response = crate::map_github_error(self.send(request).await?).await?;
+
+        let token = <ActionsIdToken as crate::FromResponse>::from_response(response).await?;
+        Ok(SecretString::from(token.value))
+    }
+}
+
+#[derive(Deserialize)]
+struct ActionsIdToken {
+    value: String,
 }
 
 /// The device codes as returned from step 1 of Github's device flow.
@@ -254,6 +654,201 @@ impl DeviceCodes {
     }
 }
 
+/// Builds the URL users are sent to to authorize an app with the web
+/// application flow, along with a random `state` and, optionally, a PKCE
+/// challenge.
+///
+/// Keep the returned [`AuthorizeUrl`] (e.g. in the user's session) to check
+/// the `state` and send the PKCE verifier when exchanging the code with
+/// [`ExchangeWebFlowCodeBuilder::authorize_url`].
+///
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// use octocrab::auth::{AuthorizeUrlBuilder, ExchangeWebFlowCodeBuilder};
+/// use secrecy::SecretString;
+///
+/// let client_id = SecretString::from("Iv1.client_id");
+/// let authorize = AuthorizeUrlBuilder::new(&client_id)
+///     .redirect_uri("https://example.com/callback")
+///     .scope("read:user")
+///     .pkce()
+///     .build()?;
+/// // Redirect the user to `authorize.url`, who comes back with `code` and `state`.
+/// # let (code, state) = ("code", "state");
+///
+/// let crab = octocrab::Octocrab::builder()
+///     .base_uri("https://github.com")?
+///     .add_header(http::header::ACCEPT, "application/json".to_string())
+///     .build()?;
+/// let oauth = ExchangeWebFlowCodeBuilder::new(
+///     &crab,
+///     &client_id,
+///     &SecretString::from("client_secret"),
+///     code,
+/// )
+/// .authorize_url(&authorize, state)
+/// .send()
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See <https://docs.github.com/en/apps/oauth-apps/building-oauth-apps/authorizing-oauth-apps#1-request-a-users-github-identity>
+#[derive(Debug, Clone)]
+pub struct AuthorizeUrlBuilder {
+    base_url: String,
+    client_id: String,
+    redirect_uri: Option<String>,
+    scopes: Vec<String>,
+    login: Option<String>,
+    allow_signup: Option<bool>,
+    state: Option<String>,
+    pkce: bool,
+}
+
+impl AuthorizeUrlBuilder {
+    pub fn new(client_id: &SecretString) -> Self {
+        Self {
+            base_url: "https://github.com".to_owned(),
+            client_id: client_id.expose_secret().to_owned(),
+            redirect_uri: None,
+            scopes: Vec::new(),
+            login: None,
+            allow_signup: None,
+            state: None,
+            pkce: false,
+        }
+    }
+
+    /// Set the URL of GitHub, e.g. `https://ghe.example.com` for GitHub
+    /// Enterprise Server. Defaults to `https://github.com`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Set the URL in your application where users are sent after
+    /// authorization.
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// Request an OAuth scope, e.g. `repo`. GitHub Apps do not use scopes.
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scopes.push(scope.into());
+        self
+    }
+
+    /// Suggest the account to sign in with.
+    pub fn login(mut self, login: impl Into<String>) -> Self {
+        self.login = Some(login.into());
+        self
+    }
+
+    /// Set whether unauthenticated users are offered to sign up for GitHub.
+    pub fn allow_signup(mut self, allow_signup: bool) -> Self {
+        self.allow_signup = Some(allow_signup);
+        self
+    }
+
+    /// Use `state` rather than a random one.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Add an `S256` PKCE challenge of a random code verifier.
+    pub fn pkce(mut self) -> Self {
+        self.pkce = true;
+        self
+    }
+
+    pub fn build(self) -> Result<AuthorizeUrl> {
+        let mut url = url::Url::parse(&format!(
+            "{}/login/oauth/authorize",
+            self.base_url.trim_end_matches('/')
+        ))
+        .map_err(|e| crate::Error::Other {
+            source: Box::new(e),
+            backtrace: Backtrace::capture(),
+        })?;
+        let state = match self.state {
+            Some(state) => state,
+            None => random_token()?,
+        };
+        let code_verifier = if self.pkce {
+            Some(SecretString::from(random_token()?))
+        } else {
+            None
+        };
+
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("client_id", &self.client_id);
+            if let Some(redirect_uri) = &self.redirect_uri {
+                query.append_pair("redirect_uri", redirect_uri);
+            }
+            if !self.scopes.is_empty() {
+                query.append_pair("scope", &self.scopes.join(" "));
+            }
+            if let Some(login) = &self.login {
+                query.append_pair("login", login);
+            }
+            if let Some(allow_signup) = self.allow_signup {
+                query.append_pair("allow_signup", &allow_signup.to_string());
+            }
+            query.append_pair("state", &state);
+            if let Some(code_verifier) = &code_verifier {
+                query.append_pair("code_challenge", &pkce_challenge(code_verifier));
+                query.append_pair("code_challenge_method", "S256");
+            }
+        }
+
+        Ok(AuthorizeUrl {
+            url,
+            state,
+            redirect_uri: self.redirect_uri,
+            code_verifier,
+        })
+    }
+}
+
+/// An authorization request of the web application flow, built by
+/// [`AuthorizeUrlBuilder`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct AuthorizeUrl {
+    /// The URL to send the user to.
+    pub url: url::Url,
+    /// The `state` GitHub must send back with the code.
+    pub state: String,
+    /// The URL users are sent back to, if any.
+    pub redirect_uri: Option<String>,
+    /// The PKCE code verifier, if a challenge was sent.
+    pub code_verifier: Option<SecretString>,
+}
+
+/// Returns 32 random bytes, encoded as URL-safe base64.
+fn random_token() -> Result<String> {
+    use base64::prelude::{Engine, BASE64_URL_SAFE_NO_PAD};
+
+    let mut bytes = [0; 32];
+    getrandom::getrandom(&mut bytes).map_err(|e| crate::Error::Other {
+        source: Box::new(e),
+        backtrace: Backtrace::capture(),
+    })?;
+    Ok(BASE64_URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Returns the `S256` challenge of a PKCE code verifier.
+fn pkce_challenge(code_verifier: &SecretString) -> String {
+    use base64::prelude::{Engine, BASE64_URL_SAFE_NO_PAD};
+    use sha2::{Digest, Sha256};
+
+    BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.expose_secret().as_bytes()))
+}
+
 /// See https://docs.github.com/en/apps/creating-github-apps/authenticating-with-a-github-app/generating-a-user-access-token-for-a-github-app#using-the-web-application-flow-to-generate-a-user-access-token
 #[derive(serde::Serialize)]
 pub struct ExchangeWebFlowCodeBuilder<
@@ -282,6 +877,9 @@ pub struct ExchangeWebFlowCodeBuilder<
     /// The ID of a single repository that the user access token can access.
     #[serde(skip_serializing_if = "Option::is_none")]
     repository_id: Option<&'repository_id str>,
+    /// Whether the `state` sent back by GitHub is the expected one.
+    #[serde(skip)]
+    state_matches: bool,
 }
 
 impl<'octo, 'client_id, 'code, 'client_secret, 'redirect_uri, 'code_verifier, 'repository_id>
@@ -309,6 +907,7 @@ impl<'octo, 'client_id, 'code, 'client_secret, 'redirect_uri, 'code_verifier, 'r
             redirect_uri: None,
             code_verifier: None,
             repository_id: None,
+            state_matches: true,
         }
     }
 
@@ -330,12 +929,40 @@ impl<'octo, 'client_id, 'code, 'client_secret, 'redirect_uri, 'code_verifier, 'r
         self
     }
 
+    /// Check that the `state` sent back by GitHub is the expected one, so
+    /// that [`send`](Self::send) fails if it is not.
+    pub fn state(mut self, expected: &str, received: &str) -> Self {
+        self.state_matches = expected == received;
+        self
+    }
+
+    /// Exchange the code of the `authorize_url` request: check the `state`
+    /// sent back by GitHub, and set the `redirect_uri` and `code_verifier`.
+    pub fn authorize_url<'authorize>(
+        self,
+        authorize_url: &'authorize AuthorizeUrl,
+        received_state: &str,
+    ) -> Self
+    where
+        'authorize: 'redirect_uri + 'code_verifier,
+    {
+        let mut builder = self.state(&authorize_url.state, received_state);
+        if let Some(redirect_uri) = &authorize_url.redirect_uri {
+            builder.redirect_uri = Some(redirect_uri);
+        }
+        if let Some(code_verifier) = &authorize_url.code_verifier {
+            builder.code_verifier = Some(code_verifier.expose_secret());
+        }
+        builder
+    }
+
     /// Sends the actual request.
     /// Exchange a code for a user access token
     ///
     /// see: https://docs.github.com/en/developers/apps/identifying-and-authorizing-users-for-github-apps
     ///
     pub async fn send(self) -> crate::Result<OAuth> {
+        ensure!(self.state_matches, crate::error::OAuthStateSnafu);
         let route = "/login/oauth/access_token";
         self.crab.post(route, Some(&self)).await
     }
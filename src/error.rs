@@ -7,6 +7,7 @@ use std::fmt::{Display, Formatter};
 use std::string::FromUtf8Error;
 use tower::BoxError;
 
+use crate::models::webhook_events::WebhookError;
 use crate::GraphqlError;
 
 //This is workaround until I figure out how to get TryInto errors to work
@@ -101,6 +102,26 @@ pub enum Error {
         source: GraphqlErrors,
         backtrace: Backtrace,
     },
+    #[snafu(display("OAuth token refresh failed: {}", message))]
+    OAuthRefresh {
+        message: String,
+        backtrace: Backtrace,
+    },
+    #[snafu(display(
+        "OAuth state mismatch: the authorization response was not requested by this client"
+    ))]
+    OAuthState { backtrace: Backtrace },
+    #[snafu(display("GitHub Actions environment variable {}: {}", name, source))]
+    ActionsEnvironment {
+        name: &'static str,
+        source: std::env::VarError,
+        backtrace: Backtrace,
+    },
+    #[snafu(display("Webhook Error: {}", source))]
+    Webhook {
+        source: WebhookError,
+        backtrace: Backtrace,
+    },
     Other {
         source: Box<dyn std::error::Error + Send + Sync>,
         backtrace: Backtrace,
@@ -0,0 +1,185 @@
+//! Clients for the many installations of a GitHub App.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use web_time::{Duration, Instant};
+
+use crate::models::InstallationId;
+use crate::{Octocrab, Result};
+
+/// How long an installation may go unused before it is evicted, by default.
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+struct PooledInstallation {
+    crab: Arc<Octocrab>,
+    last_used: Instant,
+}
+
+#[derive(Default)]
+struct PoolState {
+    installations: HashMap<InstallationId, PooledInstallation>,
+    orgs: HashMap<String, InstallationId>,
+    repos: HashMap<(String, String), InstallationId>,
+}
+
+/// A pool of clients authenticated as the installations of a GitHub App.
+///
+/// All clients share the HTTP service of the app's [`Octocrab`], and each
+/// installation's access token is only requested when a request is made,
+/// then cached until it expires. Installations which have not been used for
+/// the [idle timeout](InstallationPool::idle_timeout) are evicted, along with
+/// their token.
+///
+/// Created with [`Octocrab::installation_pool`].
+///
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// # let app = octocrab::Octocrab::default();
+/// let pool = app.installation_pool()?;
+///
+/// let issues = pool
+///     .repo("rust-lang", "rust")
+///     .await?
+///     .issues("rust-lang", "rust")
+///     .list()
+///     .send()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct InstallationPool {
+    app: Octocrab,
+    idle_timeout: Duration,
+    state: Arc<Mutex<PoolState>>,
+}
+
+impl fmt::Debug for InstallationPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstallationPool")
+            .field("app", &self.app)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl InstallationPool {
+    pub(crate) fn new(app: Octocrab) -> Self {
+        Self {
+            app,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            state: Arc::default(),
+        }
+    }
+
+    /// Sets how long an installation may go unused before it is evicted.
+    /// Defaults to one hour.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Returns the client of installation `id`.
+    pub fn installation(&self, id: InstallationId) -> Result<Arc<Octocrab>> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        self.evict_idle_at(&mut state, now);
+
+        if let Some(pooled) = state.installations.get_mut(&id) {
+            pooled.last_used = now;
+            return Ok(pooled.crab.clone());
+        }
+
+        let crab = Arc::new(self.app.installation(id)?);
+        state.installations.insert(
+            id,
+            PooledInstallation {
+                crab: crab.clone(),
+                last_used: now,
+            },
+        );
+        Ok(crab)
+    }
+
+    /// Returns the client of the installation on the organization `org`,
+    /// looking it up on first use.
+    pub async fn org(&self, org: impl AsRef<str>) -> Result<Arc<Octocrab>> {
+        let key = org.as_ref().to_lowercase();
+        let known = self.state.lock().unwrap().orgs.get(&key).copied();
+        if let Some(id) = known {
+            return self.installation(id);
+        }
+
+        let id = self.app.apps().get_org_installation(&key).await?.id;
+        // Pooled first, so that the lookup is not evicted along with it.
+        let crab = self.installation(id)?;
+        self.state.lock().unwrap().orgs.insert(key, id);
+        Ok(crab)
+    }
+
+    /// Returns the client of the installation on the repository
+    /// `owner/repo`, looking it up on first use.
+    pub async fn repo(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> Result<Arc<Octocrab>> {
+        let key = (owner.as_ref().to_lowercase(), repo.as_ref().to_lowercase());
+        let known = self.state.lock().unwrap().repos.get(&key).copied();
+        if let Some(id) = known {
+            return self.installation(id);
+        }
+
+        let id = self
+            .app
+            .apps()
+            .get_repository_installation(&key.0, &key.1)
+            .await?
+            .id;
+        let crab = self.installation(id)?;
+        self.state.lock().unwrap().repos.insert(key, id);
+        Ok(crab)
+    }
+
+    /// Removes installation `id`, e.g. after it was deleted, so that it is
+    /// looked up again by [`org`](Self::org) and [`repo`](Self::repo).
+    pub fn remove(&self, id: InstallationId) {
+        let mut state = self.state.lock().unwrap();
+        state.installations.remove(&id);
+        state.orgs.retain(|_, installation| *installation != id);
+        state.repos.retain(|_, installation| *installation != id);
+    }
+
+    /// Evicts the installations which have not been used for the idle
+    /// timeout, along with their organizations and repositories. This also
+    /// happens whenever a client is requested.
+    pub fn evict_idle(&self) {
+        let mut state = self.state.lock().unwrap();
+        self.evict_idle_at(&mut state, Instant::now());
+    }
+
+    /// Returns the number of installations in the pool.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().installations.len()
+    }
+
+    /// Returns whether the pool holds no installation.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn evict_idle_at(&self, state: &mut PoolState, now: Instant) {
+        let idle_timeout = self.idle_timeout;
+        let PoolState {
+            installations,
+            orgs,
+            repos,
+        } = state;
+        installations.retain(|_, pooled| now.duration_since(pooled.last_used) < idle_timeout);
+        orgs.retain(|_, id| installations.contains_key(id));
+        repos.retain(|_, id| installations.contains_key(id));
+    }
+}
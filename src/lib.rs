@@ -207,6 +207,9 @@
 //!     _ => warn!("Ignored event"),
 //! };
 //! ```
+//!
+//! [`service::webhook::WebhookReceiver`] wraps these steps, including signature verification,
+//! into a [`tower::Service`] that can be mounted in your HTTP server.
 #![cfg_attr(test, recursion_limit = "512")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
@@ -218,6 +221,7 @@ mod page;
 
 pub mod auth;
 pub mod etag;
+pub mod installation_pool;
 pub mod models;
 pub mod params;
 pub mod service;
@@ -226,11 +230,12 @@ use api::repos::RepoRef;
 use api::users::UserRef;
 pub use body::OctoBody;
 use chrono::{DateTime, Utc};
+use futures::{StreamExt, TryStreamExt};
 use http::{HeaderMap, HeaderValue, Method, Uri};
 use http_body_util::combinators::BoxBody;
 use http_body_util::BodyExt;
-use service::middleware::auth_header::AuthHeaderLayer;
-use service::middleware::cache::{CacheStorage, HttpCacheLayer};
+use service::middleware::auth_header::{is_trusted_authority, AuthHeaderLayer};
+use service::middleware::cache::{CacheIdentity, CacheStorage, HttpCacheLayer};
 use std::convert::{Infallible, TryInto};
 use std::future::Future;
 use std::io::Write;
@@ -279,21 +284,25 @@ use crate::error::{
 use crate::service::middleware::base_uri::BaseUriLayer;
 use crate::service::middleware::extra_headers::ExtraHeadersLayer;
 
+#[cfg(feature = "retry")]
+use crate::service::middleware::rate_limit::{RateLimitBudget, RateLimitLayer};
 #[cfg(feature = "retry")]
 use crate::service::middleware::retry::RetryConfig;
+#[cfg(feature = "retry")]
+use crate::service::middleware::throttle::{ThrottleConfig, ThrottleLayer};
 
-use auth::{AppAuth, Auth};
+use auth::{AppAuth, Auth, CredentialProvider, RefreshingToken};
 use models::{AppId, InstallationId, InstallationToken, RepositoryId, UserId};
 
 pub use self::{
     api::{
         actions, activity, apps, checks, classroom, code_scannings, commits, current, events,
         gists, gitignore, hooks, issues, licenses, markdown, orgs, projects, pulls, ratelimit,
-        repos, search, teams, users, workflows,
+        repos, rulesets, search, teams, users, workflows,
     },
     error::{Error, GitHubError},
     from_response::FromResponse,
-    page::Page,
+    page::{Page, PageCursor, Paginate},
 };
 
 #[cfg(all(feature = "jwt-rust-crypto", feature = "jwt-aws-lc-rs"))]
@@ -467,6 +476,40 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
     pub fn new() -> Self {
         OctocrabBuilder::default()
     }
+
+    /// Returns a builder configured from the environment of a GitHub Actions
+    /// workflow: authenticated with `GITHUB_TOKEN`, and using the REST and
+    /// GraphQL endpoints of `GITHUB_API_URL` and `GITHUB_GRAPHQL_URL`, which
+    /// point at GitHub Enterprise Server when the workflow runs there.
+    ///
+    /// `GITHUB_TOKEN` is not set by default, it must be passed to the step:
+    ///
+    /// ```yaml
+    /// env:
+    ///   GITHUB_TOKEN: ${{ secrets.GITHUB_TOKEN }}
+    /// ```
+    pub fn from_actions_env() -> Result<Self> {
+        let var = |name: &'static str| {
+            std::env::var(name).context(error::ActionsEnvironmentSnafu { name })
+        };
+        let optional_var = |name: &'static str| std::env::var(name).ok();
+
+        let mut builder = Self::new().personal_token(var("GITHUB_TOKEN")?);
+        if let Some(api_url) = optional_var("GITHUB_API_URL") {
+            builder = builder.base_uri(api_url)?;
+        }
+        if let Some(graphql_url) = optional_var("GITHUB_GRAPHQL_URL") {
+            builder = builder.graphql_uri(graphql_url)?;
+        }
+        // Uploads have their own host on github.com only.
+        if let Some(server_url) = optional_var("GITHUB_SERVER_URL")
+            .filter(|server_url| server_url.trim_end_matches('/') != "https://github.com")
+        {
+            builder =
+                builder.upload_uri(format!("{}/api/uploads", server_url.trim_end_matches('/')))?;
+        }
+        Ok(builder)
+    }
 }
 
 impl<Config, Auth> OctocrabBuilder<NoSvc, Config, Auth, NotLayerReady> {
@@ -569,10 +612,15 @@ where
         .map_err(|e| e.into());
 
         if let Some(executor) = self.executor {
-            return Ok(Octocrab::new_with_executor(service, self.auth, executor));
+            return Ok(Octocrab::new_with_executor(
+                service,
+                self.auth,
+                default_trusted_hosts(),
+                executor,
+            ));
         }
 
-        Ok(Octocrab::new(service, self.auth))
+        Ok(Octocrab::new(service, self.auth, default_trusted_hosts()))
     }
 }
 
@@ -597,6 +645,24 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         self
     }
 
+    /// Hold back requests which would exhaust their rate limit budget, see
+    /// [`RateLimitBudget`].
+    #[cfg(feature = "retry")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "retry")))]
+    pub fn rate_limit_budget(mut self, budget: RateLimitBudget) -> Self {
+        self.config.rate_limit_budget = Some(budget);
+        self
+    }
+
+    /// Cap in-flight requests and space out mutating requests, to avoid
+    /// secondary rate limits. See [`ThrottleConfig`].
+    #[cfg(feature = "retry")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "retry")))]
+    pub fn throttle(mut self, throttle: ThrottleConfig) -> Self {
+        self.config.throttle = Some(throttle);
+        self
+    }
+
     /// Set the connect timeout.
     #[cfg(feature = "timeout")]
     #[cfg_attr(docsrs, doc(cfg(feature = "timeout")))]
@@ -659,6 +725,22 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         self
     }
 
+    /// Authenticate with an expiring user access token, which is refreshed
+    /// automatically. See [`auth::OAuthRefresh`].
+    pub fn oauth_refresh(mut self, refresh: auth::OAuthRefresh) -> Self {
+        self.config.auth = Auth::OAuthRefresh(refresh);
+        self
+    }
+
+    /// Authenticate with credentials supplied by `provider` for each request.
+    pub fn credential_provider<P>(mut self, provider: P) -> Self
+    where
+        P: auth::CredentialProvider + 'static,
+    {
+        self.config.auth = Auth::CredentialProvider(Arc::new(provider));
+        self
+    }
+
     /// Authenticate with a user access token.
     pub fn user_access_token<S: Into<SecretString>>(mut self, token: S) -> Self {
         self.config.auth = Auth::UserAccessToken(token.into());
@@ -687,6 +769,27 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         Ok(self)
     }
 
+    /// Set the GraphQL endpoint for `Octocrab`, e.g.
+    /// `https://ghe.example.com/api/graphql` for GitHub Enterprise Server.
+    /// Defaults to `/graphql` on the base url.
+    pub fn graphql_uri(mut self, graphql_uri: impl TryInto<Uri>) -> Result<Self> {
+        self.config.graphql_uri = Some(
+            graphql_uri
+                .try_into()
+                .map_err(|_| UriParseError {})
+                .context(UriParseSnafu)?,
+        );
+        Ok(self)
+    }
+
+    /// Also send credentials to `host` when following absolute URLs, such as
+    /// pagination links or redirects. The hosts of the base and upload urls
+    /// are always trusted. A `host` without a port matches any port.
+    pub fn add_trusted_host(mut self, host: impl Into<String>) -> Self {
+        self.config.trusted_hosts.push(host.into());
+        self
+    }
+
     pub fn cache<C>(mut self, cache: C) -> Self
     where
         C: CacheStorage + 'static,
@@ -758,8 +861,11 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
                 .build(connector)
         };
 
+        // The throttle sits below the retry layer, so that retried requests
+        // are throttled as well.
         #[cfg(feature = "retry")]
-        let client = self.set_connector_retry_service(client);
+        let client = RetryLayer::new(self.config.retry_config.clone())
+            .layer(ThrottleLayer::new(self.config.throttle.as_ref()).layer(client));
 
         #[cfg(feature = "tracing")]
         let client = TraceLayer::new_for_http()
@@ -853,6 +959,11 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
                 ),
                 AuthState::None,
             ),
+            Auth::CredentialProvider(provider) => (None, AuthState::Provider(provider)),
+            Auth::OAuthRefresh(refresh) => (
+                None,
+                AuthState::RefreshingOAuth(Arc::new(RefreshingToken::from(refresh))),
+            ),
         };
 
         for (key, value) in self.config.extra_headers.iter() {
@@ -885,15 +996,39 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
 
         let client = BaseUriLayer::new(base_uri.clone()).layer(client);
 
-        let client = AuthHeaderLayer::new(auth_header, base_uri, upload_uri).layer(client);
+        // Below the auth header layer, so that budgets are tracked per
+        // credentials.
+        #[cfg(feature = "retry")]
+        let client = RateLimitLayer::new(self.config.rate_limit_budget.clone()).layer(client);
 
+        // The cache sits below the auth header layer so that cached responses
+        // are partitioned by the credentials they were fetched with.
         let client = HttpCacheLayer::new(self.config.cache_storage.clone()).layer(client);
 
-        if let Some(executor) = self.executor {
-            return Ok(Octocrab::new_with_executor(client, auth_state, executor));
-        }
+        let trusted_hosts: Arc<[String]> = base_uri
+            .authority()
+            .into_iter()
+            .chain(upload_uri.authority())
+            .chain(self.config.graphql_uri.as_ref().and_then(Uri::authority))
+            .map(|authority| authority.to_string())
+            .chain(self.config.trusted_hosts)
+            .collect();
+
+        let client = AuthHeaderLayer::new(auth_header, base_uri, upload_uri)
+            .with_trusted_hosts(trusted_hosts.iter().cloned())
+            .layer(client);
+
+        let octocrab = match self.executor {
+            Some(executor) => {
+                Octocrab::new_with_executor(client, auth_state, trusted_hosts, executor)
+            }
+            None => Octocrab::new(client, auth_state, trusted_hosts),
+        };
 
-        Ok(Octocrab::new(client, auth_state))
+        Ok(Octocrab {
+            graphql_uri: self.config.graphql_uri.unwrap_or_else(default_graphql_uri),
+            ..octocrab
+        })
     }
 }
 
@@ -909,9 +1044,15 @@ pub struct DefaultOctocrabBuilderConfig {
     write_timeout: Option<Duration>,
     base_uri: Option<Uri>,
     upload_uri: Option<Uri>,
+    graphql_uri: Option<Uri>,
     #[cfg(feature = "retry")]
     retry_config: RetryConfig,
+    #[cfg(feature = "retry")]
+    rate_limit_budget: Option<RateLimitBudget>,
+    #[cfg(feature = "retry")]
+    throttle: Option<ThrottleConfig>,
     cache_storage: Option<Arc<dyn CacheStorage>>,
+    trusted_hosts: Vec<String>,
 }
 
 impl Default for DefaultOctocrabBuilderConfig {
@@ -928,9 +1069,15 @@ impl Default for DefaultOctocrabBuilderConfig {
             write_timeout: None,
             base_uri: None,
             upload_uri: None,
+            graphql_uri: None,
             #[cfg(feature = "retry")]
             retry_config: RetryConfig::Simple(3),
+            #[cfg(feature = "retry")]
+            rate_limit_budget: None,
+            #[cfg(feature = "retry")]
+            throttle: None,
             cache_storage: None,
+            trusted_hosts: Vec::new(),
         }
     }
 }
@@ -1043,13 +1190,136 @@ pub enum AuthState {
         /// The cached access token, if any
         token: CachedToken,
     },
+    /// Authentication via a Github App installation, with an access token
+    /// restricted to some repositories and permissions
+    ScopedInstallation {
+        /// The app authentication data (app ID and private key)
+        app: AppAuth,
+        /// The installation ID
+        installation: InstallationId,
+        /// The cached access token, if any
+        token: CachedToken,
+        /// The restrictions of the access token
+        scope: auth::InstallationTokenScope,
+    },
     /// Access token based authentication.
     AccessToken {
         /// The access token
         token: SecretString,
     },
+    /// Expiring user access token, refreshed automatically
+    RefreshingOAuth(Arc<RefreshingToken>),
+    /// Credentials supplied by a user-defined [`auth::CredentialProvider`]
+    Provider(Arc<dyn auth::CredentialProvider>),
+}
+
+#[async_trait::async_trait]
+impl CredentialProvider for AuthState {
+    async fn authorization(&self, crab: &Octocrab) -> Result<Option<HeaderValue>> {
+        let auth_header = match self {
+            AuthState::None => None,
+            AuthState::App(app) => Some(
+                HeaderValue::from_str(format!("Bearer {}", app.generate_bearer_token()?).as_str())
+                    .map_err(http::Error::from)
+                    .context(HttpSnafu)?,
+            ),
+            AuthState::BasicAuth { username, password } => {
+                // Equivalent implementation of: https://github.com/seanmonstar/reqwest/blob/df2b3baadc1eade54b1c22415792b778442673a4/src/util.rs#L3-L23
+                use base64::prelude::BASE64_STANDARD;
+                use base64::write::EncoderWriter;
+
+                let mut buf = b"Basic ".to_vec();
+                {
+                    let mut encoder = EncoderWriter::new(&mut buf, &BASE64_STANDARD);
+                    write!(encoder, "{username}:{password}").expect("writing to a Vec never fails");
+                }
+                Some(HeaderValue::from_bytes(&buf).expect("base64 is always valid HeaderValue"))
+            }
+            AuthState::Installation { token, .. } | AuthState::ScopedInstallation { token, .. } => {
+                let token = if let Some(token) = token.valid_token() {
+                    token
+                } else {
+                    crab.request_installation_auth_token().await?
+                };
+
+                Some(
+                    HeaderValue::from_str(format!("Bearer {}", token.expose_secret()).as_str())
+                        .map_err(http::Error::from)
+                        .context(HttpSnafu)?,
+                )
+            }
+            AuthState::AccessToken { token } => Some(
+                HeaderValue::from_str(format!("Bearer {}", token.expose_secret()).as_str())
+                    .map_err(http::Error::from)
+                    .context(HttpSnafu)?,
+            ),
+            AuthState::RefreshingOAuth(refreshing) => Some(
+                HeaderValue::from_str(refreshing.authorization(crab).await?.as_str())
+                    .map_err(http::Error::from)
+                    .context(HttpSnafu)?,
+            ),
+            AuthState::Provider(provider) => provider.authorization(crab).await?,
+        };
+        Ok(auth_header)
+    }
+
+    fn unauthorized(&self) {
+        match self {
+            AuthState::Installation { token, .. } | AuthState::ScopedInstallation { token, .. } => {
+                token.clear()
+            }
+            AuthState::Provider(provider) => provider.unauthorized(),
+            _ => {}
+        }
+    }
+
+    fn identity(&self) -> Option<CacheIdentity> {
+        match self {
+            AuthState::App(app) => Some(CacheIdentity::new(format!("app:{}", app.app_id))),
+            AuthState::Installation { installation, .. } => {
+                Some(CacheIdentity::new(format!("installation:{installation}")))
+            }
+            // Responses fetched with a down-scoped token must not be served
+            // to a token of the full installation, or another scope.
+            AuthState::ScopedInstallation {
+                installation,
+                scope,
+                ..
+            } => Some(CacheIdentity::new(format!(
+                "installation:{installation}:{}",
+                serde_json::to_string(scope).ok()?
+            ))),
+            AuthState::Provider(provider) => provider.identity(),
+            _ => None,
+        }
+    }
 }
 
+/// Request extension marking a non-idempotent request (e.g. a `POST`) as safe
+/// to retry.
+///
+/// By default, requests whose method is not idempotent are only retried when
+/// GitHub did not process them (connection failures and rate limits), so that
+/// a retry never duplicates a side effect. The builders creating or updating
+/// issues, pull requests, releases and commit comments can opt in with their
+/// `retryable` method; for other requests, insert this extension to also retry
+/// them on server errors and timeouts:
+///
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// let octocrab = octocrab::instance();
+/// let mut request = octocrab.build_request(
+///     http::Request::post("/repos/owner/repo/labels"),
+///     Some(&serde_json::json!({ "name": "bug" })),
+/// )?;
+/// request.extensions_mut().insert(octocrab::Retryable);
+/// let response = octocrab.execute(request).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Retryable;
+
 pub type OctocrabService = Buffer<
     http::Request<OctoBody>,
     <BoxService<http::Request<OctoBody>, http::Response<BoxBody<Bytes, Error>>, BoxError> as tower::Service<http::Request<OctoBody>>>::Future
@@ -1060,6 +1330,18 @@ pub type OctocrabService = Buffer<
 pub struct Octocrab {
     client: OctocrabService,
     auth_state: AuthState,
+    trusted_hosts: Arc<[String]>,
+    graphql_uri: Uri,
+}
+
+/// The GraphQL endpoint, relative to the base url.
+fn default_graphql_uri() -> Uri {
+    Uri::from_static("/graphql")
+}
+
+/// Hosts credentials are sent to when no base url is known.
+fn default_trusted_hosts() -> Arc<[String]> {
+    Arc::new(["api.github.com".to_owned()])
 }
 
 impl fmt::Debug for Octocrab {
@@ -1091,7 +1373,7 @@ impl Octocrab {
     }
 
     /// Creates a new `Octocrab`.
-    fn new<S>(service: S, auth_state: AuthState) -> Self
+    fn new<S>(service: S, auth_state: AuthState, trusted_hosts: Arc<[String]>) -> Self
     where
         S: Service<Request<OctoBody>, Response = Response<BoxBody<Bytes, crate::Error>>>
             + Send
@@ -1104,11 +1386,18 @@ impl Octocrab {
         Self {
             client: service,
             auth_state,
+            trusted_hosts,
+            graphql_uri: default_graphql_uri(),
         }
     }
 
     /// Creates a new `Octocrab` with a custom executor
-    fn new_with_executor<S>(service: S, auth_state: AuthState, executor: Executor) -> Self
+    fn new_with_executor<S>(
+        service: S,
+        auth_state: AuthState,
+        trusted_hosts: Arc<[String]>,
+        executor: Executor,
+    ) -> Self
     where
         S: Service<Request<OctoBody>, Response = Response<BoxBody<Bytes, crate::Error>>>
             + Send
@@ -1125,6 +1414,8 @@ impl Octocrab {
         Self {
             client: service,
             auth_state,
+            trusted_hosts,
+            graphql_uri: default_graphql_uri(),
         }
     }
 
@@ -1136,6 +1427,38 @@ impl Octocrab {
     /// obtain a new `Octocrab` with which you can make API calls
     /// with the permissions of that installation.
     pub fn installation(&self, id: InstallationId) -> Result<Octocrab> {
+        self.installation_with_scope(id, None)
+    }
+
+    /// Similar to `installation`, but the access tokens of the returned
+    /// `Octocrab` are restricted to the repositories and permissions of
+    /// `scope`, for jobs which need less than the whole installation.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let app = octocrab::Octocrab::default();
+    /// use octocrab::{auth::InstallationTokenScope, models::InstallationId};
+    ///
+    /// let scope = InstallationTokenScope::new()
+    ///     .repository("octocrab")
+    ///     .permission("contents", "read");
+    /// let octocrab = app.scoped_installation(InstallationId(1), scope)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scoped_installation(
+        &self,
+        id: InstallationId,
+        scope: auth::InstallationTokenScope,
+    ) -> Result<Octocrab> {
+        self.installation_with_scope(id, Some(scope))
+    }
+
+    fn installation_with_scope(
+        &self,
+        id: InstallationId,
+        scope: Option<auth::InstallationTokenScope>,
+    ) -> Result<Octocrab> {
         let app_auth = if let AuthState::App(ref app_auth) = self.auth_state {
             app_auth.clone()
         } else {
@@ -1143,16 +1466,40 @@ impl Octocrab {
                 backtrace: Backtrace::capture(),
             });
         };
-        Ok(Octocrab {
-            client: self.client.clone(),
-            auth_state: AuthState::Installation {
+        let token = CachedToken::default();
+        let auth_state = match scope {
+            Some(scope) => AuthState::ScopedInstallation {
+                app: app_auth,
+                installation: id,
+                token,
+                scope,
+            },
+            None => AuthState::Installation {
                 app: app_auth,
                 installation: id,
-                token: CachedToken::default(),
+                token,
             },
+        };
+        Ok(Octocrab {
+            client: self.client.clone(),
+            trusted_hosts: self.trusted_hosts.clone(),
+            graphql_uri: self.graphql_uri.clone(),
+            auth_state,
         })
     }
 
+    /// Returns an [`InstallationPool`](installation_pool::InstallationPool)
+    /// holding clients for the installations of the current Github App,
+    /// which share this client's HTTP service.
+    pub fn installation_pool(&self) -> Result<installation_pool::InstallationPool> {
+        if !matches!(self.auth_state, AuthState::App(_)) {
+            return Err(Error::Installation {
+                backtrace: Backtrace::capture(),
+            });
+        }
+        Ok(installation_pool::InstallationPool::new(self.clone()))
+    }
+
     /// Similar to `installation`, but also eagerly caches the installation
     /// token and returns the token. The returned token can be used to make
     /// https git requests to e.g. clone repositories that the installation
@@ -1168,6 +1515,18 @@ impl Octocrab {
         Ok((crab, token))
     }
 
+    /// Similar to `scoped_installation`, but also eagerly caches the
+    /// down-scoped installation token and returns the token.
+    pub async fn scoped_installation_and_token(
+        &self,
+        id: InstallationId,
+        scope: auth::InstallationTokenScope,
+    ) -> Result<(Octocrab, SecretString)> {
+        let crab = self.scoped_installation(id, scope)?;
+        let token = crab.request_installation_auth_token().await?;
+        Ok((crab, token))
+    }
+
     /// Acquire a GitHub App installation access token that does not expire for
     /// at least 30 seconds. A cached token will be used if its expiration is
     /// far enough in the future. Otherwise, a new token will be acquired and
@@ -1185,7 +1544,9 @@ impl Octocrab {
         &self,
         buffer: chrono::Duration,
     ) -> Result<SecretString> {
-        let token = if let AuthState::Installation { ref token, .. } = self.auth_state {
+        let token = if let AuthState::Installation { ref token, .. }
+        | AuthState::ScopedInstallation { ref token, .. } = self.auth_state
+        {
             token
         } else {
             return Err(Error::InstallationTokenInvalidAuth {
@@ -1208,6 +1569,8 @@ impl Octocrab {
     pub fn user_access_token<S: Into<SecretString>>(&self, token: S) -> Result<Self> {
         Ok(Octocrab {
             client: self.client.clone(),
+            trusted_hosts: self.trusted_hosts.clone(),
+            graphql_uri: self.graphql_uri.clone(),
             auth_state: AuthState::AccessToken {
                 token: token.into(),
             },
@@ -1433,7 +1796,10 @@ impl Octocrab {
         payload: &(impl serde::Serialize + ?Sized),
     ) -> crate::Result<R> {
         let response: GraphqlResponse<R> = self
-            .post("/graphql", Some(&serde_json::json!(payload)))
+            .post(
+                self.graphql_uri.to_string(),
+                Some(&serde_json::json!(payload)),
+            )
             .await?;
 
         match response {
@@ -1525,6 +1891,24 @@ impl Octocrab {
         R::from_response(crate::map_github_error(response).await?).await
     }
 
+    /// Same as [`Octocrab::post`] or [`Octocrab::patch`] depending on
+    /// `method`, but marks the request as [`Retryable`] if `retryable` is set.
+    pub(crate) async fn send_mutation<P: Serialize + ?Sized, R: FromResponse>(
+        &self,
+        method: Method,
+        route: impl AsRef<str>,
+        body: Option<&P>,
+        retryable: bool,
+    ) -> Result<R> {
+        let uri = self.parameterized_uri(route, None::<&()>)?;
+        let mut request = self.build_request(Builder::new().method(method).uri(uri), body)?;
+        if retryable {
+            request.extensions_mut().insert(Retryable);
+        }
+        let response = self.execute(request).await?;
+        R::from_response(crate::map_github_error(response).await?).await
+    }
+
     /// Send a `POST` request with no additional pre/post-processing.
     pub async fn _post<P: Serialize + ?Sized>(
         &self,
@@ -1752,17 +2136,23 @@ impl Octocrab {
 
     /// Requests a fresh installation auth token and caches it. Returns the token.
     async fn request_installation_auth_token(&self) -> Result<SecretString> {
-        let (app, installation, token) = if let AuthState::Installation {
-            ref app,
-            installation,
-            ref token,
-        } = self.auth_state
-        {
-            (app, installation, token)
-        } else {
-            return Err(Error::Installation {
-                backtrace: Backtrace::capture(),
-            });
+        let (app, installation, token, scope) = match self.auth_state {
+            AuthState::Installation {
+                ref app,
+                installation,
+                ref token,
+            } => (app, installation, token, None),
+            AuthState::ScopedInstallation {
+                ref app,
+                installation,
+                ref token,
+                ref scope,
+            } => (app, installation, token, Some(scope)),
+            _ => {
+                return Err(Error::Installation {
+                    backtrace: Backtrace::capture(),
+                })
+            }
         };
         let mut request = Builder::new();
         let mut sensitive_value =
@@ -1780,8 +2170,12 @@ impl Octocrab {
             .header(http::header::AUTHORIZATION, sensitive_value)
             .method(http::Method::POST)
             .uri(uri);
+        let body = match scope {
+            Some(scope) => serde_json::to_string(scope).context(SerdeSnafu)?,
+            None => "{}".to_owned(),
+        };
         let response = self
-            .send(request.body("{}".into()).context(HttpSnafu)?)
+            .send(request.body(body.into()).context(HttpSnafu)?)
             .await?;
         let _status = response.status();
 
@@ -1839,81 +2233,80 @@ impl Octocrab {
     ) -> Result<http::Response<BoxBody<Bytes, crate::Error>>> {
         let (mut parts, body) = request.into_parts();
         let body: OctoBody = body.into();
-        // Saved request that we can retry later if necessary
-        let auth_header: Option<HeaderValue> = match self.auth_state {
-            AuthState::None => None,
-            AuthState::App(ref app) => Some(
-                HeaderValue::from_str(format!("Bearer {}", app.generate_bearer_token()?).as_str())
-                    .map_err(http::Error::from)
-                    .context(HttpSnafu)?,
-            ),
-            AuthState::BasicAuth {
-                ref username,
-                ref password,
-            } => {
-                // Equivalent implementation of: https://github.com/seanmonstar/reqwest/blob/df2b3baadc1eade54b1c22415792b778442673a4/src/util.rs#L3-L23
-                use base64::prelude::BASE64_STANDARD;
-                use base64::write::EncoderWriter;
-
-                let mut buf = b"Basic ".to_vec();
-                {
-                    let mut encoder = EncoderWriter::new(&mut buf, &BASE64_STANDARD);
-                    write!(encoder, "{username}:{password}").expect("writing to a Vec never fails");
-                }
-                Some(HeaderValue::from_bytes(&buf).expect("base64 is always valid HeaderValue"))
-            }
-            AuthState::Installation { ref token, .. } => {
-                let token = if let Some(token) = token.valid_token() {
-                    token
-                } else {
-                    self.request_installation_auth_token().await?
-                };
-
-                Some(
-                    HeaderValue::from_str(format!("Bearer {}", token.expose_secret()).as_str())
-                        .map_err(http::Error::from)
-                        .context(HttpSnafu)?,
-                )
-            }
-            AuthState::AccessToken { ref token } => Some(
-                HeaderValue::from_str(format!("Bearer {}", token.expose_secret()).as_str())
-                    .map_err(http::Error::from)
-                    .context(HttpSnafu)?,
-            ),
-        };
+        // Partition cached responses by app or installation rather than by
+        // their short-lived tokens.
+        if let Some(identity) = self.auth_state.identity() {
+            parts.extensions.insert(identity);
+        }
+        let auth_header = self.auth_state.authorization(self).await?;
 
         if let Some(mut auth_header) = auth_header {
-            // Only set the auth_header if the authority (host) is api.github.com or empty (destined for
+            // Only set the auth_header if the authority (host) is trusted or empty (destined for
             // GitHub). Otherwise, leave it off as we could have been redirected
             // away from GitHub (via follow_location_to_data()), and we don't
             // want to give our credentials to third-party services.
-            match parts.uri.authority() {
-                None => {
-                    auth_header.set_sensitive(true);
-                    parts
-                        .headers
-                        .insert(http::header::AUTHORIZATION, auth_header);
-                }
-                Some(authority) if authority == "api.github.com" => {
-                    auth_header.set_sensitive(true);
-                    parts
-                        .headers
-                        .insert(http::header::AUTHORIZATION, auth_header);
-                }
-                Some(_) => {
-                    // Don't insert auth header.
-                }
+            let trusted = match parts.uri.authority() {
+                None => true,
+                Some(authority) => is_trusted_authority(authority, &self.trusted_hosts),
+            };
+            if trusted {
+                auth_header.set_sensitive(true);
+                parts
+                    .headers
+                    .insert(http::header::AUTHORIZATION, auth_header);
             }
         }
 
+        // An expired user access token is refreshed, and the request replayed
+        // once, if its body can be cloned.
+        let replay = match self.auth_state {
+            AuthState::RefreshingOAuth(ref refreshing) => refreshing
+                .refreshable_token()
+                .zip(
+                    body.try_clone()
+                        .or_else(|| http_body::Body::is_end_stream(&body).then(OctoBody::empty)),
+                )
+                .map(|(stale, body)| {
+                    (
+                        stale,
+                        parts.method.clone(),
+                        parts.uri.clone(),
+                        parts.headers.clone(),
+                        parts.extensions.clone(),
+                        body,
+                    )
+                }),
+            _ => None,
+        };
+
         let request = http::Request::from_parts(parts, body);
 
         let response = self.send(request).await?;
 
         let status = response.status();
         if StatusCode::UNAUTHORIZED == status {
-            if let AuthState::Installation { ref token, .. } = self.auth_state {
-                token.clear();
+            self.auth_state.unauthorized();
+            if let AuthState::RefreshingOAuth(ref refreshing) = self.auth_state {
+                if let Some((stale, method, uri, mut headers, extensions, body)) = replay {
+                    refreshing.refresh(self, &stale).await?;
+                    if headers.contains_key(http::header::AUTHORIZATION) {
+                        let mut auth_header =
+                            HeaderValue::from_str(&refreshing.authorization(self).await?)
+                                .map_err(http::Error::from)
+                                .context(HttpSnafu)?;
+                        auth_header.set_sensitive(true);
+                        headers.insert(http::header::AUTHORIZATION, auth_header);
+                    }
+
+                    let mut request = Builder::new()
+                        .method(method)
+                        .uri(uri)
+                        .body(body)
+                        .context(HttpSnafu)?;
+                    *request.headers_mut() = headers;
+                    *request.extensions_mut() = extensions;
+                    return self.send(request).await;
+                }
             }
         }
         Ok(response)
@@ -1979,6 +2372,15 @@ impl Octocrab {
         }
     }
 
+    /// Gets the page at `cursor`, to resume a listing saved with
+    /// [`Page::cursor`].
+    pub async fn resume<R: serde::de::DeserializeOwned>(
+        &self,
+        cursor: &PageCursor,
+    ) -> crate::Result<Page<R>> {
+        self.get(cursor.next().to_string(), None::<&()>).await
+    }
+
     /// A convenience method to get all the results starting at a given
     /// page.
     pub async fn all_pages<R: serde::de::DeserializeOwned>(
@@ -1992,6 +2394,48 @@ impl Octocrab {
         }
         Ok(ret)
     }
+
+    /// Like [`Octocrab::all_pages`], but fetches the remaining pages up to
+    /// `max_concurrent` at a time, instead of one after another. The items
+    /// are returned in the same order.
+    ///
+    /// The remaining pages are found from the `last` link of `page`, so
+    /// responses without one are fetched one after another.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab
+    ///     .orgs("rust-lang")
+    ///     .list_repos()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// let repos = octocrab.all_pages_concurrent(page, 8).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_pages_concurrent<R: serde::de::DeserializeOwned>(
+        &self,
+        mut page: Page<R>,
+        max_concurrent: usize,
+    ) -> crate::Result<Vec<R>> {
+        let remaining = match page.remaining_pages()? {
+            Some(remaining) => remaining,
+            None => return self.all_pages(page).await,
+        };
+
+        let mut ret = page.take_items();
+        let pages: Vec<Page<R>> = futures::stream::iter(remaining)
+            .map(|uri| self.get(uri.to_string(), None::<&()>))
+            .buffered(max_concurrent.max(1))
+            .try_collect()
+            .await?;
+        for mut next_page in pages {
+            ret.append(&mut next_page.take_items());
+        }
+        Ok(ret)
+    }
 }
 
 #[cfg(test)]
@@ -29,6 +29,7 @@ pub mod orgs_copilot;
 pub mod pulls;
 pub mod reactions;
 pub mod repos;
+pub mod rulesets;
 pub mod search;
 pub mod teams;
 pub mod timelines;
@@ -114,6 +115,10 @@ id_type!(
     CheckRunId,
     CodeScanningId,
     CommentId,
+    DeploymentBranchPolicyId,
+    DeploymentId,
+    DeploymentStatusId,
+    EnvironmentId,
     InstallationId,
     IssueEventId,
     IssueId,
@@ -132,6 +137,8 @@ id_type!(
     ReleaseId,
     RepositoryId,
     ReviewId,
+    RuleSuiteId,
+    RulesetId,
     RunId,
     RunnerId,
     RunnerGroupId,
@@ -1,3 +1,9 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use secrecy::{ExposeSecret, SecretString};
+use snafu::ResultExt;
+
 use super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -122,3 +128,282 @@ pub struct Permissions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workflows: Option<String>,
 }
+
+/// The configuration of a GitHub App to register with the [manifest flow].
+///
+/// Post it as the `manifest` form field to
+/// `https://github.com/settings/apps/new` (or
+/// `https://github.com/organizations/{org}/settings/apps/new`), then exchange
+/// the `code` GitHub redirects back with using
+/// [`AppsRequestHandler::create_from_manifest`](crate::apps::AppsRequestHandler::create_from_manifest).
+///
+/// ```
+/// use octocrab::models::apps::AppManifest;
+///
+/// let manifest = AppManifest::new("https://example.com")
+///     .name("Provisioned app")
+///     .hook_url("https://example.com/webhooks")
+///     .redirect_url("https://example.com/manifest/redirect")
+///     .default_permission("issues", "write")
+///     .default_event("issues");
+/// let form_field = serde_json::to_string(&manifest).unwrap();
+/// ```
+///
+/// [manifest flow]: https://docs.github.com/en/apps/sharing-github-apps/registering-a-github-app-from-a-manifest
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AppManifest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hook_attributes: Option<AppManifestHookAttributes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    callback_urls: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    setup_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    public: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    default_events: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    default_permissions: BTreeMap<String, String>,
+    request_oauth_on_install: bool,
+    setup_on_update: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct AppManifestHookAttributes {
+    url: String,
+    active: bool,
+}
+
+impl AppManifest {
+    /// Creates a private app whose homepage is `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            url: url.into(),
+            hook_attributes: None,
+            redirect_url: None,
+            callback_urls: Vec::new(),
+            setup_url: None,
+            description: None,
+            public: false,
+            default_events: Vec::new(),
+            default_permissions: BTreeMap::new(),
+            request_oauth_on_install: false,
+            setup_on_update: false,
+        }
+    }
+
+    /// The name of the app, which users may change on GitHub.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The URL webhook events are delivered to.
+    pub fn hook_url(mut self, url: impl Into<String>) -> Self {
+        self.hook_attributes = Some(AppManifestHookAttributes {
+            url: url.into(),
+            active: true,
+        });
+        self
+    }
+
+    /// The URL GitHub redirects to with the `code` of the created app.
+    pub fn redirect_url(mut self, url: impl Into<String>) -> Self {
+        self.redirect_url = Some(url.into());
+        self
+    }
+
+    /// A URL users are redirected to after authorizing an installation.
+    pub fn callback_url(mut self, url: impl Into<String>) -> Self {
+        self.callback_urls.push(url.into());
+        self
+    }
+
+    /// The URL users are redirected to after installing the app.
+    pub fn setup_url(mut self, url: impl Into<String>) -> Self {
+        self.setup_url = Some(url.into());
+        self
+    }
+
+    /// The description of the app.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether anyone may install the app, rather than only its owner.
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = public;
+        self
+    }
+
+    /// Subscribes the app to webhook `event`, e.g. `pull_request`.
+    pub fn default_event(mut self, event: impl Into<String>) -> Self {
+        self.default_events.push(event.into());
+        self
+    }
+
+    /// Requests `level` (`read` or `write`) access to `permission`, e.g.
+    /// `contents`.
+    pub fn default_permission(
+        mut self,
+        permission: impl Into<String>,
+        level: impl Into<String>,
+    ) -> Self {
+        self.default_permissions
+            .insert(permission.into(), level.into());
+        self
+    }
+
+    /// Whether users authorize the app when installing it.
+    pub fn request_oauth_on_install(mut self, request_oauth_on_install: bool) -> Self {
+        self.request_oauth_on_install = request_oauth_on_install;
+        self
+    }
+
+    /// Whether users are redirected to the setup URL after updating an
+    /// installation.
+    pub fn setup_on_update(mut self, setup_on_update: bool) -> Self {
+        self.setup_on_update = setup_on_update;
+        self
+    }
+}
+
+/// A GitHub App created from an [`AppManifest`], along with its credentials.
+///
+/// The secrets are moved out of [`app`](Self::app), and are only exposed by
+/// their accessors.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "App")]
+#[non_exhaustive]
+pub struct AppManifestConversion {
+    pub app: App,
+    client_secret: SecretString,
+    webhook_secret: Option<SecretString>,
+    pem: SecretString,
+}
+
+impl TryFrom<App> for AppManifestConversion {
+    type Error = &'static str;
+
+    fn try_from(mut app: App) -> Result<Self, Self::Error> {
+        let client_secret = app
+            .client_secret
+            .take()
+            .ok_or("missing field `client_secret`")?;
+        let pem = app.pem.take().ok_or("missing field `pem`")?;
+        let webhook_secret = app.webhook_secret.take();
+        Ok(Self {
+            app,
+            client_secret: client_secret.into(),
+            webhook_secret: webhook_secret.map(SecretString::from),
+            pem: pem.into(),
+        })
+    }
+}
+
+impl AppManifestConversion {
+    /// The client secret of the app, to authenticate its users with OAuth.
+    pub fn client_secret(&self) -> &SecretString {
+        &self.client_secret
+    }
+
+    /// The secret signing the webhook deliveries, if the manifest has one.
+    pub fn webhook_secret(&self) -> Option<&SecretString> {
+        self.webhook_secret.as_ref()
+    }
+
+    /// The private key of the app, in PEM format.
+    pub fn pem(&self) -> &SecretString {
+        &self.pem
+    }
+
+    /// Parses the private key of the app, to authenticate with
+    /// [`OctocrabBuilder::app`](crate::OctocrabBuilder::app).
+    pub fn encoding_key(&self) -> crate::Result<jsonwebtoken::EncodingKey> {
+        jsonwebtoken::EncodingKey::from_rsa_pem(self.pem.expose_secret().as_bytes())
+            .context(crate::error::JWTSnafu)
+    }
+}
+
+/// An authorization of an OAuth app or GitHub App by a user, as returned by
+/// the [OAuth application endpoints](crate::apps::AppsRequestHandler::check_token).
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+pub struct OAuthAuthorization {
+    pub id: u64,
+    pub url: Url,
+    pub scopes: Option<Vec<String>>,
+    #[serde(deserialize_with = "deserialize_secret")]
+    token: SecretString,
+    pub token_last_eight: Option<String>,
+    pub hashed_token: Option<String>,
+    pub app: OAuthAuthorizationApp,
+    pub note: Option<String>,
+    pub note_url: Option<Url>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub fingerprint: Option<String>,
+    pub user: Option<Author>,
+    pub installation: Option<ScopedInstallation>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl OAuthAuthorization {
+    /// The access token. It is only returned in full when it was just
+    /// created or reset.
+    pub fn token(&self) -> &SecretString {
+        &self.token
+    }
+
+    /// Returns the access token, to authenticate with
+    /// [`OctocrabBuilder::oauth`](crate::OctocrabBuilder::oauth).
+    pub fn oauth(&self) -> crate::auth::OAuth {
+        crate::auth::OAuth {
+            access_token: self.token.clone(),
+            token_type: "bearer".to_owned(),
+            scope: self.scopes.clone().unwrap_or_default(),
+            expires_in: None,
+            refresh_token: None,
+            refresh_token_expires_in: None,
+        }
+    }
+}
+
+/// The app an [`OAuthAuthorization`] was granted to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct OAuthAuthorizationApp {
+    pub client_id: String,
+    pub name: String,
+    pub url: Url,
+}
+
+/// The installation a scoped user access token is restricted to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ScopedInstallation {
+    pub permissions: InstallationPermissions,
+    pub repository_selection: String,
+    pub single_file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_multiple_single_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_file_paths: Option<Vec<String>>,
+    pub repositories_url: Url,
+    pub account: Author,
+}
+
+fn deserialize_secret<'de, D>(deserializer: D) -> Result<SecretString, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(SecretString::from)
+}
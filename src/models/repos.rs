@@ -7,7 +7,11 @@ use hyper::Response;
 use snafu::ResultExt;
 use url::Url;
 
+pub mod branch_protection;
 pub mod dependabot;
+pub mod deployments;
+pub mod environments;
+pub mod git;
 pub mod secret_scanning_alert;
 pub mod secrets;
 
@@ -298,6 +302,13 @@ pub struct Branch {
     pub name: String,
     pub commit: CommitObject,
     pub protected: bool,
+    /// The protection of the branch. When listing branches, it only carries
+    /// whether protection is `enabled` and the required status checks
+    /// summary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protection: Option<branch_protection::BranchProtection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protection_url: Option<Url>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
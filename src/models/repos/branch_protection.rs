@@ -0,0 +1,160 @@
+use super::super::*;
+use crate::models::apps::App;
+use crate::models::teams::Team;
+
+/// The classic protection of a branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BranchProtection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_status_checks: Option<RequiredStatusChecks>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_admins: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_signatures: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<BranchRestrictions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_linear_history: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_force_pushes: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_deletions: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_creations: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_conversation_resolution: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_branch: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_fork_syncing: Option<ProtectionSetting>,
+}
+
+/// A setting of a [`BranchProtection`] which is either enabled or not.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProtectionSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    pub enabled: bool,
+}
+
+/// The status checks which must pass before merging into a branch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RequiredStatusChecks {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforcement_level: Option<String>,
+    /// Whether branches must be up to date with the base branch before
+    /// merging. Missing from the summary embedded in a branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+    #[serde(default)]
+    pub contexts: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts_url: Option<Url>,
+    #[serde(default)]
+    pub checks: Vec<StatusCheck>,
+}
+
+/// A status check which must pass, set by the app `app_id` if any.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StatusCheck {
+    pub context: String,
+    pub app_id: Option<AppId>,
+}
+
+impl StatusCheck {
+    pub fn new(context: impl Into<String>, app_id: Option<AppId>) -> Self {
+        Self {
+            context: context.into(),
+            app_id,
+        }
+    }
+}
+
+/// The reviews which pull requests need before merging into a branch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RequiredPullRequestReviews {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// The users, teams and apps which can dismiss reviews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions: Option<BranchRestrictions>,
+    pub dismiss_stale_reviews: bool,
+    pub require_code_owner_reviews: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_approving_review_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_last_push_approval: Option<bool>,
+    /// The users, teams and apps which can bypass the required reviews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_pull_request_allowances: Option<BranchRestrictions>,
+}
+
+/// The users, teams and apps allowed to push to a branch, or to dismiss or
+/// bypass reviews.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BranchRestrictions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teams_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apps_url: Option<Url>,
+    #[serde(default)]
+    pub users: Vec<Author>,
+    #[serde(default)]
+    pub teams: Vec<Team>,
+    #[serde(default)]
+    pub apps: Vec<App>,
+}
+
+/// The status checks to require, when updating a [`BranchProtection`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RequiredStatusChecksUpdate {
+    pub strict: bool,
+    pub checks: Vec<StatusCheck>,
+}
+
+/// The reviews to require, when updating a [`BranchProtection`]. The fields
+/// which are `None` are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RequiredPullRequestReviewsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions: Option<RestrictionsUpdate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismiss_stale_reviews: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_code_owner_reviews: Option<bool>,
+    /// Between 0 and 6.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_approving_review_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_last_push_approval: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_pull_request_allowances: Option<RestrictionsUpdate>,
+}
+
+/// The logins of the users, the slugs of the teams and the slugs of the apps
+/// to restrict a branch to, when updating a [`BranchProtection`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RestrictionsUpdate {
+    pub users: Vec<String>,
+    pub teams: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apps: Option<Vec<String>>,
+}
@@ -0,0 +1,97 @@
+use super::super::*;
+
+/// A request to deploy a specific ref of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Deployment {
+    pub id: DeploymentId,
+    pub node_id: String,
+    pub url: Url,
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    pub task: String,
+    /// Extra information set when creating the deployment, either a JSON
+    /// object or a string.
+    pub payload: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_environment: Option<String>,
+    pub environment: String,
+    pub description: Option<String>,
+    pub creator: Option<Author>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub statuses_url: Url,
+    pub repository_url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transient_environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub production_environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<App>,
+}
+
+/// The status of a [`Deployment`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentStatus {
+    pub id: DeploymentStatusId,
+    pub node_id: String,
+    pub url: Url,
+    pub state: DeploymentState,
+    pub creator: Option<Author>,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    pub target_url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deployment_url: Url,
+    pub repository_url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<App>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentState {
+    Error,
+    Failure,
+    Inactive,
+    InProgress,
+    Queued,
+    Pending,
+    Success,
+}
+
+/// The outcome of [creating](crate::repos::deployments::CreateDeploymentBuilder)
+/// a deployment.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CreateDeploymentResponse {
+    /// The deployment was created.
+    Created(Box<Deployment>),
+    /// The default branch was merged into the ref instead, because the ref
+    /// was behind and `auto_merge` is enabled. No deployment was created, so
+    /// it has to be requested again.
+    AutoMerged {
+        /// The message of GitHub, e.g. `Auto-merged main into topic on
+        /// deployment.`
+        message: String,
+    },
+}
+
+/// The review of a custom deployment protection rule, which lets a
+/// deployment waiting on the rule proceed or not.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProtectionRuleReviewState {
+    Approved,
+    Rejected,
+}
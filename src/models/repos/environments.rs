@@ -0,0 +1,90 @@
+use super::super::*;
+use crate::models::teams::RequestedTeam;
+
+/// A deployment environment of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Environment {
+    pub id: EnvironmentId,
+    pub node_id: String,
+    pub name: String,
+    pub url: Url,
+    pub html_url: Url,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protection_rules: Option<Vec<EnvironmentProtectionRule>>,
+    /// The branches which can deploy to the environment, or `None` if all
+    /// branches can.
+    pub deployment_branch_policy: Option<DeploymentBranchPolicySettings>,
+}
+
+/// A protection rule of an [`Environment`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EnvironmentProtectionRule {
+    pub id: u64,
+    pub node_id: String,
+    /// The type of the rule, e.g. `wait_timer`, `required_reviewers` or
+    /// `branch_policy`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The minutes to wait before deploying, for `wait_timer` rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timer: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_self_review: Option<bool>,
+    /// The reviewers of deployments, for `required_reviewers` rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<Vec<EnvironmentReviewer>>,
+}
+
+/// A user or team which can review the deployments to an environment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "reviewer")]
+#[non_exhaustive]
+pub enum EnvironmentReviewer {
+    User(Box<Author>),
+    Team(Box<RequestedTeam>),
+}
+
+/// A user or team which must review the deployments to an environment, when
+/// creating or updating it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub enum RequiredReviewer {
+    User { id: UserId },
+    Team { id: TeamId },
+}
+
+/// The branches which can deploy to an environment. Exactly one of the
+/// fields must be `true`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentBranchPolicySettings {
+    /// Whether only branches with branch protection rules can deploy.
+    pub protected_branches: bool,
+    /// Whether only the branches matching the custom
+    /// [`DeploymentBranchPolicy`]s can deploy.
+    pub custom_branch_policies: bool,
+}
+
+/// A name pattern of the branches or tags which can deploy to an environment
+/// with custom branch policies.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentBranchPolicy {
+    pub id: DeploymentBranchPolicyId,
+    pub node_id: String,
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<DeploymentBranchPolicyType>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentBranchPolicyType {
+    Branch,
+    Tag,
+}
@@ -0,0 +1,157 @@
+use super::super::*;
+
+/// A blob of the Git database, i.e. the content of a file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Blob {
+    pub sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    pub url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    pub content: String,
+    /// Either `base64` or `utf-8`.
+    pub encoding: String,
+}
+
+impl Blob {
+    /// Decodes the content of the blob, returning `None` if its base64
+    /// encoding is invalid.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blob = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_blob("3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15")
+    ///     .await?;
+    /// let bytes = blob.decoded_content().unwrap_or_default();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decoded_content(&self) -> Option<Vec<u8>> {
+        use base64::Engine;
+        if self.encoding != "base64" {
+            return Some(self.content.as_bytes().to_owned());
+        }
+        let mut content = self.content.as_bytes().to_owned();
+        content.retain(|b| !b" \n\t\r\x0b\x0c".contains(b));
+        base64::prelude::BASE64_STANDARD.decode(content).ok()
+    }
+}
+
+/// A reference to a blob which was created.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BlobRef {
+    pub sha: String,
+    pub url: Url,
+}
+
+/// A tree of the Git database, i.e. the content of a directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Tree {
+    pub sha: String,
+    pub url: Url,
+    /// Whether the entries were truncated, when getting a tree recursively.
+    #[serde(default)]
+    pub truncated: bool,
+    pub tree: Vec<TreeEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TreeEntry {
+    pub path: String,
+    pub mode: FileMode,
+    #[serde(rename = "type")]
+    pub kind: TreeEntryType,
+    pub sha: String,
+    /// The size of the blob, for blob entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+}
+
+/// The mode of a [`TreeEntry`].
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum FileMode {
+    #[serde(rename = "100644")]
+    File,
+    #[serde(rename = "100755")]
+    Executable,
+    #[serde(rename = "040000")]
+    Subdirectory,
+    #[serde(rename = "160000")]
+    Submodule,
+    #[serde(rename = "120000")]
+    Symlink,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TreeEntryType {
+    Blob,
+    Tree,
+    Commit,
+}
+
+/// An entry to write to, or delete from, a tree when creating it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TreeEntryUpdate {
+    path: String,
+    mode: FileMode,
+    #[serde(rename = "type")]
+    kind: TreeEntryType,
+    #[serde(flatten)]
+    source: TreeEntrySource,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum TreeEntrySource {
+    Sha(Option<String>),
+    Content(String),
+}
+
+impl TreeEntryUpdate {
+    /// Writes the UTF-8 `content` to a new blob at `path`.
+    pub fn content(path: impl Into<String>, mode: FileMode, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode,
+            kind: TreeEntryType::Blob,
+            source: TreeEntrySource::Content(content.into()),
+        }
+    }
+
+    /// Writes the existing object `sha`, e.g. a blob created with
+    /// [`RepoHandler::create_blob`](crate::repos::RepoHandler::create_blob),
+    /// at `path`.
+    pub fn object(
+        path: impl Into<String>,
+        mode: FileMode,
+        kind: TreeEntryType,
+        sha: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            mode,
+            kind,
+            source: TreeEntrySource::Sha(Some(sha.into())),
+        }
+    }
+
+    /// Deletes the file at `path` from the base tree.
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: FileMode::File,
+            kind: TreeEntryType::Blob,
+            source: TreeEntrySource::Sha(None),
+        }
+    }
+}
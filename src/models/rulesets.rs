@@ -0,0 +1,547 @@
+use super::*;
+
+/// A ruleset enforcing rules on the branches, tags or pushes of a repository,
+/// or of the repositories of an organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Ruleset {
+    pub id: RulesetId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<RulesetTarget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<RulesetSourceType>,
+    /// The name of the repository or organization which owns the ruleset.
+    pub source: String,
+    pub enforcement: RulesetEnforcement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_actors: Option<Vec<BypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_user_can_bypass: Option<CurrentUserCanBypass>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RulesetConditions>,
+    /// The rules of the ruleset, only present when getting a single ruleset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<Rule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RulesetTarget {
+    Branch,
+    Tag,
+    Push,
+    Repository,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum RulesetSourceType {
+    Repository,
+    Organization,
+    Enterprise,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RulesetEnforcement {
+    Disabled,
+    Active,
+    /// Only evaluates the rules without enforcing them, available to
+    /// organizations on GitHub Enterprise.
+    Evaluate,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CurrentUserCanBypass {
+    Always,
+    PullRequestsOnly,
+    Never,
+    Exempt,
+    /// A bypass setting this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// An actor which can bypass the rules of a ruleset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BypassActor {
+    /// The id of the actor, which is ignored for the `OrganizationAdmin`
+    /// and `DeployKey` actor types.
+    pub actor_id: Option<u64>,
+    pub actor_type: BypassActorType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_mode: Option<BypassMode>,
+}
+
+impl BypassActor {
+    pub fn new(actor_id: Option<u64>, actor_type: BypassActorType) -> Self {
+        Self {
+            actor_id,
+            actor_type,
+            bypass_mode: None,
+        }
+    }
+
+    pub fn bypass_mode(mut self, bypass_mode: BypassMode) -> Self {
+        self.bypass_mode = Some(bypass_mode);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum BypassActorType {
+    Integration,
+    OrganizationAdmin,
+    RepositoryRole,
+    Team,
+    DeployKey,
+    EnterpriseOwner,
+    /// An actor type this version of the crate doesn't know about, kept as
+    /// is so that it can be sent back.
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BypassMode {
+    Always,
+    PullRequest,
+    Exempt,
+    /// A bypass mode this version of the crate doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The refs and repositories a ruleset applies to.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RulesetConditions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_name: Option<RefNameCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_name: Option<RepositoryNameCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_id: Option<RepositoryIdCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_property: Option<RepositoryPropertyCondition>,
+}
+
+impl RulesetConditions {
+    pub fn ref_name(mut self, ref_name: RefNameCondition) -> Self {
+        self.ref_name = Some(ref_name);
+        self
+    }
+
+    pub fn repository_name(mut self, repository_name: RepositoryNameCondition) -> Self {
+        self.repository_name = Some(repository_name);
+        self
+    }
+
+    pub fn repository_id(mut self, repository_id: RepositoryIdCondition) -> Self {
+        self.repository_id = Some(repository_id);
+        self
+    }
+
+    pub fn repository_property(mut self, repository_property: RepositoryPropertyCondition) -> Self {
+        self.repository_property = Some(repository_property);
+        self
+    }
+}
+
+/// The refs a ruleset applies to. The patterns are fully qualified ref names
+/// or globs, e.g. `refs/heads/main` or `refs/heads/release/**`, and
+/// `~DEFAULT_BRANCH` or `~ALL`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefNameCondition {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+/// The names of the repositories an organization ruleset applies to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryNameCondition {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    /// Whether renaming a targeted repository is prevented.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>,
+}
+
+/// The repositories an organization ruleset applies to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryIdCondition {
+    pub repository_ids: Vec<RepositoryId>,
+}
+
+/// The custom property values of the repositories an organization ruleset
+/// applies to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryPropertyCondition {
+    pub include: Vec<RepositoryPropertyTarget>,
+    pub exclude: Vec<RepositoryPropertyTarget>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RepositoryPropertyTarget {
+    pub name: String,
+    pub property_values: Vec<String>,
+    /// Either `custom` or `system`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl RepositoryPropertyTarget {
+    pub fn new(name: impl Into<String>, property_values: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            property_values,
+            source: None,
+        }
+    }
+}
+
+/// A rule of a [`Ruleset`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "parameters", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Rule {
+    /// Only users with bypass permission can create matching refs.
+    Creation,
+    /// Only users with bypass permission can update matching refs.
+    Update(Option<UpdateParameters>),
+    /// Only users with bypass permission can delete matching refs.
+    Deletion,
+    /// Prevents merge commits from being pushed to matching refs.
+    RequiredLinearHistory,
+    MergeQueue(MergeQueueParameters),
+    /// Requires deployments to the environments to succeed before merging
+    /// into matching refs.
+    RequiredDeployments(RequiredDeploymentsParameters),
+    /// Requires commits pushed to matching refs to have verified signatures.
+    RequiredSignatures,
+    PullRequest(PullRequestParameters),
+    RequiredStatusChecks(RequiredStatusChecksParameters),
+    /// Prevents users with push access from force pushing to matching refs.
+    NonFastForward,
+    CommitMessagePattern(PatternParameters),
+    CommitAuthorEmailPattern(PatternParameters),
+    CommitterEmailPattern(PatternParameters),
+    BranchNamePattern(PatternParameters),
+    TagNamePattern(PatternParameters),
+    /// Prevents commits including changes to the file paths from being
+    /// pushed, for push rulesets.
+    FilePathRestriction(FilePathRestrictionParameters),
+    MaxFilePathLength(MaxFilePathLengthParameters),
+    FileExtensionRestriction(FileExtensionRestrictionParameters),
+    MaxFileSize(MaxFileSizeParameters),
+    /// Requires the workflows to succeed before merging into matching refs.
+    Workflows(WorkflowsParameters),
+    /// Requires the code scanning tools to report results before merging
+    /// into matching refs.
+    CodeScanning(CodeScanningParameters),
+    /// A rule this version of the crate doesn't know about, with its raw
+    /// parameters.
+    #[serde(untagged)]
+    Other {
+        #[serde(rename = "type")]
+        rule_type: String,
+        #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+        parameters: serde_json::Value,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateParameters {
+    /// Whether the branch can be pulled from the upstream repository when it
+    /// is locked.
+    pub update_allows_fetch_and_merge: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeQueueParameters {
+    pub check_response_timeout_minutes: u32,
+    pub grouping_strategy: MergeQueueGroupingStrategy,
+    pub max_entries_to_build: u32,
+    pub max_entries_to_merge: u32,
+    pub merge_method: MergeQueueMergeMethod,
+    pub min_entries_to_merge: u32,
+    pub min_entries_to_merge_wait_minutes: u32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum MergeQueueGroupingStrategy {
+    /// All the entries of a merge group must pass their required checks.
+    AllGreen,
+    /// Only the head entry of a merge group must pass its required checks.
+    HeadGreen,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum MergeQueueMergeMethod {
+    Merge,
+    Squash,
+    Rebase,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequiredDeploymentsParameters {
+    pub required_deployment_environments: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequestParameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_merge_methods: Option<Vec<AllowedMergeMethod>>,
+    pub dismiss_stale_reviews_on_push: bool,
+    pub require_code_owner_review: bool,
+    pub require_last_push_approval: bool,
+    pub required_approving_review_count: u32,
+    pub required_review_thread_resolution: bool,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AllowedMergeMethod {
+    Merge,
+    Squash,
+    Rebase,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequiredStatusChecksParameters {
+    /// Whether refs can be created even though the checks would fail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub do_not_enforce_on_create: Option<bool>,
+    pub required_status_checks: Vec<StatusCheckConfiguration>,
+    /// Whether pull requests must be tested with the latest code of the
+    /// targeted branch.
+    pub strict_required_status_checks_policy: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StatusCheckConfiguration {
+    pub context: String,
+    /// The integration which must set the status check, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<AppId>,
+}
+
+impl StatusCheckConfiguration {
+    pub fn new(context: impl Into<String>) -> Self {
+        Self {
+            context: context.into(),
+            integration_id: None,
+        }
+    }
+
+    pub fn integration_id(mut self, integration_id: impl Into<AppId>) -> Self {
+        self.integration_id = Some(integration_id.into());
+        self
+    }
+}
+
+/// The parameters of the rules restricting names and emails with a pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatternParameters {
+    /// How the rule appears when shown to users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the rule fails when the pattern matches, instead of when it
+    /// does not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub negate: Option<bool>,
+    pub operator: PatternOperator,
+    pub pattern: String,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PatternOperator {
+    StartsWith,
+    EndsWith,
+    Contains,
+    Regex,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilePathRestrictionParameters {
+    pub restricted_file_paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaxFilePathLengthParameters {
+    pub max_file_path_length: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileExtensionRestrictionParameters {
+    pub restricted_file_extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaxFileSizeParameters {
+    /// The maximum file size in megabytes.
+    pub max_file_size: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowsParameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub do_not_enforce_on_create: Option<bool>,
+    pub workflows: Vec<WorkflowFileReference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WorkflowFileReference {
+    pub path: String,
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    pub repository_id: RepositoryId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+}
+
+impl WorkflowFileReference {
+    pub fn new(path: impl Into<String>, repository_id: impl Into<RepositoryId>) -> Self {
+        Self {
+            path: path.into(),
+            reference: None,
+            repository_id: repository_id.into(),
+            sha: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeScanningParameters {
+    pub code_scanning_tools: Vec<CodeScanningTool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeScanningTool {
+    pub tool: String,
+    pub alerts_threshold: AlertsThreshold,
+    pub security_alerts_threshold: SecurityAlertsThreshold,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AlertsThreshold {
+    None,
+    Errors,
+    ErrorsAndWarnings,
+    All,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SecurityAlertsThreshold {
+    None,
+    Critical,
+    HighOrHigher,
+    MediumOrHigher,
+    All,
+}
+
+/// A rule applying to a branch, with the ruleset it comes from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BranchRule {
+    #[serde(flatten)]
+    pub rule: Rule,
+    pub ruleset_source_type: RulesetSourceType,
+    pub ruleset_source: String,
+    pub ruleset_id: RulesetId,
+}
+
+/// The evaluation of the rules for a push.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RuleSuite {
+    pub id: RuleSuiteId,
+    pub actor_id: Option<ActorId>,
+    pub actor_name: Option<String>,
+    pub before_sha: String,
+    pub after_sha: String,
+    #[serde(rename = "ref")]
+    pub reference: String,
+    pub repository_id: RepositoryId,
+    pub repository_name: String,
+    pub pushed_at: DateTime<Utc>,
+    /// The result of the active rules, i.e. whether the push was allowed.
+    pub result: RuleSuiteResult,
+    /// The result of the rules in `evaluate` mode, had they been active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evaluation_result: Option<RuleSuiteResult>,
+    /// The evaluation of each rule, only present when getting a single
+    /// rule suite.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_evaluations: Option<Vec<RuleEvaluation>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RuleSuiteResult {
+    Pass,
+    Fail,
+    Bypass,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RuleEvaluation {
+    pub rule_source: RuleSource,
+    /// Either `active`, `evaluate` or `deleted ruleset`.
+    pub enforcement: String,
+    pub result: RuleEvaluationResult,
+    pub rule_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RuleSource {
+    /// Either `ruleset` or `protected_branch`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RuleEvaluationResult {
+    Pass,
+    Fail,
+}
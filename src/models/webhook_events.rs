@@ -10,6 +10,9 @@
 //! and then pass the value, along with the payload, to [`WebhookEvent::try_from_header_and_body`]
 //! which will validate the payload and return a valid [`WebhookEvent`].
 //!
+//! If the webhook is configured with a secret, prefer [`WebhookEvent::try_from_verified_request`],
+//! which also checks the signature of the delivery with a [`WebhookVerifier`] before parsing it.
+//!
 //! ```
 //! use octocrab::models::{AppId, webhook_events::{WebhookEvent, WebhookEventPayload, WebhookEventType}};
 //!
@@ -66,11 +69,15 @@
 //! received.
 
 pub mod payload;
+pub mod signature;
 
 use super::{orgs::Organization, Author, Installation, InstallationId, Repository, RepositoryId};
+use crate::error::{SerdeSnafu, WebhookSnafu};
 use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
 
 pub use payload::WebhookEventPayload;
+pub use signature::{WebhookError, WebhookVerifier};
 
 /// A GitHub webhook event.
 ///
@@ -134,6 +141,33 @@ impl WebhookEvent {
             specific,
         })
     }
+
+    /// Verify the signature of a webhook delivery with `verifier`, then deserialize its body
+    /// according to the `X-GitHub-Event` header.
+    ///
+    /// `body` must be the raw request body exactly as received, since the signature is computed
+    /// over its bytes. Deliveries with a missing or invalid signature are rejected with
+    /// [`Error::Webhook`](crate::Error::Webhook).
+    pub fn try_from_verified_request<B>(
+        verifier: &WebhookVerifier,
+        headers: &http::HeaderMap,
+        body: &B,
+    ) -> crate::Result<Self>
+    where
+        B: AsRef<[u8]> + ?Sized,
+    {
+        verifier
+            .verify(headers, body.as_ref())
+            .context(WebhookSnafu)?;
+
+        let kind = headers
+            .get(signature::EVENT_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(WebhookError::MissingHeader(signature::EVENT_HEADER))
+            .context(WebhookSnafu)?;
+
+        Self::try_from_header_and_body(kind, body).context(SerdeSnafu)
+    }
 }
 
 /// Kind of webhook event.
@@ -1177,6 +1211,50 @@ mod tests {
         assert_eq!(ping_event.hook.unwrap().id, 423885699);
     }
 
+    #[test]
+    fn deserialize_verified_ping() {
+        use hmac::{Hmac, Mac};
+
+        let json = include_str!("../../tests/resources/ping_webhook_event.json");
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(b"webhook secret").unwrap();
+        mac.update(json.as_bytes());
+        let signature: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert("X-GitHub-Event", "ping".parse().unwrap());
+        headers.insert(
+            "X-Hub-Signature-256",
+            format!("sha256={signature}").parse().unwrap(),
+        );
+
+        let event = WebhookEvent::try_from_verified_request(
+            &WebhookVerifier::new("webhook secret"),
+            &headers,
+            json,
+        )
+        .unwrap();
+        assert_eq!(event.kind, WebhookEventType::Ping);
+
+        let err = WebhookEvent::try_from_verified_request(
+            &WebhookVerifier::new("another secret"),
+            &headers,
+            json,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Webhook {
+                source: WebhookError::SignatureMismatch,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn deserialize_pull_request_closed() {
         let json = include_str!("../../tests/resources/pull_request_closed_webhook_event.json");
@@ -84,6 +84,7 @@ pub use self::{
     workflow_job::*, workflow_run::*,
 };
 
+use super::WebhookEventType;
 use serde::{Deserialize, Serialize};
 
 /// The specific part of the payload in a webhook event
@@ -160,6 +161,107 @@ pub enum WebhookEventPayload {
     Unknown(Box<serde_json::Value>),
 }
 
+/// A specific webhook payload type, i.e. the content of one of the [`WebhookEventPayload`] variants.
+///
+/// This allows to work with a single kind of event in a strongly typed way, for example when
+/// registering handlers on a [`WebhookReceiver`](crate::service::webhook::WebhookReceiver).
+pub trait WebhookPayload: Sized + Send + 'static {
+    /// The kind of event this payload is delivered with.
+    fn kind() -> WebhookEventType;
+
+    /// Extracts the specific payload, giving the payload back if it is of another kind.
+    fn from_payload(payload: WebhookEventPayload) -> Result<Self, WebhookEventPayload>;
+}
+
+macro_rules! impl_webhook_payload {
+    ($($variant:ident => $payload:ty,)*) => {
+        $(
+            impl WebhookPayload for $payload {
+                fn kind() -> WebhookEventType {
+                    WebhookEventType::$variant
+                }
+
+                fn from_payload(payload: WebhookEventPayload) -> Result<Self, WebhookEventPayload> {
+                    match payload {
+                        WebhookEventPayload::$variant(payload) => Ok(*payload),
+                        other => Err(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_webhook_payload! {
+    BranchProtectionRule => BranchProtectionRuleWebhookEventPayload,
+    CheckRun => CheckRunWebhookEventPayload,
+    CheckSuite => CheckSuiteWebhookEventPayload,
+    CodeScanningAlert => CodeScanningAlertWebhookEventPayload,
+    CommitComment => CommitCommentWebhookEventPayload,
+    Create => CreateWebhookEventPayload,
+    Delete => DeleteWebhookEventPayload,
+    DependabotAlert => DependabotAlertWebhookEventPayload,
+    DeployKey => DeployKeyWebhookEventPayload,
+    Deployment => DeploymentWebhookEventPayload,
+    DeploymentProtectionRule => DeploymentProtectionRuleWebhookEventPayload,
+    DeploymentStatus => DeploymentStatusWebhookEventPayload,
+    Discussion => DiscussionWebhookEventPayload,
+    DiscussionComment => DiscussionCommentWebhookEventPayload,
+    Fork => ForkWebhookEventPayload,
+    GithubAppAuthorization => GithubAppAuthorizationWebhookEventPayload,
+    Gollum => GollumWebhookEventPayload,
+    Installation => InstallationWebhookEventPayload,
+    InstallationRepositories => InstallationRepositoriesWebhookEventPayload,
+    InstallationTarget => InstallationTargetWebhookEventPayload,
+    IssueComment => IssueCommentWebhookEventPayload,
+    Issues => IssuesWebhookEventPayload,
+    Label => LabelWebhookEventPayload,
+    MarketplacePurchase => MarketplacePurchaseWebhookEventPayload,
+    Member => MemberWebhookEventPayload,
+    Membership => MembershipWebhookEventPayload,
+    MergeGroup => MergeGroupWebhookEventPayload,
+    Meta => MetaWebhookEventPayload,
+    Milestone => MilestoneWebhookEventPayload,
+    OrgBlock => OrgBlockWebhookEventPayload,
+    Organization => OrganizationWebhookEventPayload,
+    Package => PackageWebhookEventPayload,
+    PageBuild => PageBuildWebhookEventPayload,
+    PersonalAccessTokenRequest => PersonalAccessTokenRequestWebhookEventPayload,
+    Ping => PingWebhookEventPayload,
+    ProjectCard => ProjectCardWebhookEventPayload,
+    Project => ProjectWebhookEventPayload,
+    ProjectColumn => ProjectColumnWebhookEventPayload,
+    ProjectsV2 => ProjectsV2WebhookEventPayload,
+    ProjectsV2Item => ProjectsV2ItemWebhookEventPayload,
+    Public => PublicWebhookEventPayload,
+    PullRequest => PullRequestWebhookEventPayload,
+    PullRequestReview => PullRequestReviewWebhookEventPayload,
+    PullRequestReviewComment => PullRequestReviewCommentWebhookEventPayload,
+    PullRequestReviewThread => PullRequestReviewThreadWebhookEventPayload,
+    Push => PushWebhookEventPayload,
+    RegistryPackage => RegistryPackageWebhookEventPayload,
+    Release => ReleaseWebhookEventPayload,
+    RepositoryAdvisory => RepositoryAdvisoryWebhookEventPayload,
+    Repository => RepositoryWebhookEventPayload,
+    RepositoryDispatch => RepositoryDispatchWebhookEventPayload,
+    RepositoryImport => RepositoryImportWebhookEventPayload,
+    RepositoryVulnerabilityAlert => RepositoryVulnerabilityAlertWebhookEventPayload,
+    Schedule => ScheduleWebhookEventPayload,
+    SecretScanningAlert => SecretScanningAlertWebhookEventPayload,
+    SecretScanningAlertLocation => SecretScanningAlertLocationWebhookEventPayload,
+    SecurityAdvisory => SecurityAdvisoryWebhookEventPayload,
+    SecurityAndAnalysis => SecurityAndAnalysisWebhookEventPayload,
+    Sponsorship => SponsorshipWebhookEventPayload,
+    Star => StarWebhookEventPayload,
+    Status => StatusWebhookEventPayload,
+    TeamAdd => TeamAddWebhookEventPayload,
+    Team => TeamWebhookEventPayload,
+    Watch => WatchWebhookEventPayload,
+    WorkflowDispatch => WorkflowDispatchWebhookEventPayload,
+    WorkflowJob => WorkflowJobWebhookEventPayload,
+    WorkflowRun => WorkflowRunWebhookEventPayload,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -0,0 +1,236 @@
+//! Verification of the signatures GitHub attaches to webhook deliveries.
+//!
+//! When a webhook is configured with a secret, GitHub signs every delivery with an HMAC of the raw
+//! request body, and sends the hex digest in the
+//! [`X-Hub-Signature-256`](https://docs.github.com/en/webhooks/using-webhooks/validating-webhook-deliveries)
+//! header (and, for backwards compatibility, a SHA-1 digest in `X-Hub-Signature`).
+//!
+//! ```
+//! use http::HeaderMap;
+//! use octocrab::models::webhook_events::WebhookVerifier;
+//!
+//! let mut headers = HeaderMap::new();
+//! headers.insert(
+//!     "X-Hub-Signature-256",
+//!     "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+//!         .parse()
+//!         .unwrap(),
+//! );
+//!
+//! let verifier = WebhookVerifier::new("It's a Secret to Everybody");
+//! assert!(verifier.verify(&headers, b"Hello, World!").is_ok());
+//! assert!(verifier.verify(&headers, b"Goodbye, World!").is_err());
+//! ```
+
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use http::HeaderMap;
+use secrecy::{ExposeSecret, SecretString};
+use sha1::Sha1;
+use sha2::Sha256;
+
+/// Header containing the HMAC-SHA256 signature of the delivery body.
+pub const SIGNATURE_256_HEADER: &str = "x-hub-signature-256";
+/// Legacy header containing the HMAC-SHA1 signature of the delivery body.
+pub const SIGNATURE_HEADER: &str = "x-hub-signature";
+/// Header containing the name of the event that triggered the delivery.
+pub const EVENT_HEADER: &str = "x-github-event";
+/// Header containing the unique identifier of the delivery.
+pub const DELIVERY_HEADER: &str = "x-github-delivery";
+
+/// The reason a webhook delivery was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WebhookError {
+    /// A required header was absent from the request (or was not valid UTF-8).
+    MissingHeader(&'static str),
+    /// The signature header was present but not in the `<algorithm>=<hex digest>` format.
+    MalformedSignature,
+    /// The signature does not match the body for any of the configured secrets.
+    SignatureMismatch,
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::MissingHeader(name) => write!(f, "missing `{name}` header"),
+            WebhookError::MalformedSignature => write!(f, "malformed signature header"),
+            WebhookError::SignatureMismatch => write!(f, "signature does not match payload"),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+/// Verifies webhook signatures against one or more webhook secrets.
+///
+/// Several secrets can be registered so that a secret can be rotated without
+/// dropping deliveries signed with the previous one. Digests are compared in
+/// constant time.
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    secrets: Vec<SecretString>,
+    allow_sha1: bool,
+}
+
+impl WebhookVerifier {
+    /// Creates a verifier accepting deliveries signed with `secret`.
+    pub fn new(secret: impl Into<SecretString>) -> Self {
+        Self {
+            secrets: vec![secret.into()],
+            allow_sha1: false,
+        }
+    }
+
+    /// Also accept deliveries signed with `secret`, e.g. while rotating to a
+    /// new webhook secret.
+    pub fn add_secret(mut self, secret: impl Into<SecretString>) -> Self {
+        self.secrets.push(secret.into());
+        self
+    }
+
+    /// Fall back to the legacy `X-Hub-Signature` (HMAC-SHA1) header when the
+    /// `X-Hub-Signature-256` header is absent. Disabled by default.
+    pub fn allow_legacy_sha1(mut self, allow: bool) -> Self {
+        self.allow_sha1 = allow;
+        self
+    }
+
+    /// Checks that `body` was signed by GitHub with one of the configured
+    /// secrets.
+    pub fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), WebhookError> {
+        if let Some(signature) = headers.get(SIGNATURE_256_HEADER) {
+            let digest = parse_signature(signature.as_bytes(), "sha256")?;
+            self.verify_with::<Hmac<Sha256>>(&digest, body)
+        } else if let Some(signature) = headers.get(SIGNATURE_HEADER).filter(|_| self.allow_sha1) {
+            let digest = parse_signature(signature.as_bytes(), "sha1")?;
+            self.verify_with::<Hmac<Sha1>>(&digest, body)
+        } else {
+            Err(WebhookError::MissingHeader(SIGNATURE_256_HEADER))
+        }
+    }
+
+    fn verify_with<M: Mac + hmac::digest::KeyInit>(
+        &self,
+        digest: &[u8],
+        body: &[u8],
+    ) -> Result<(), WebhookError> {
+        let matched = self.secrets.iter().any(|secret| {
+            let mut mac = <M as Mac>::new_from_slice(secret.expose_secret().as_bytes())
+                .expect("HMAC can take keys of any size");
+            mac.update(body);
+            mac.verify_slice(digest).is_ok()
+        });
+
+        if matched {
+            Ok(())
+        } else {
+            Err(WebhookError::SignatureMismatch)
+        }
+    }
+}
+
+/// Parses a `<algorithm>=<hex digest>` header value into the raw digest.
+fn parse_signature(value: &[u8], algorithm: &str) -> Result<Vec<u8>, WebhookError> {
+    let hex = std::str::from_utf8(value)
+        .ok()
+        .and_then(|value| value.strip_prefix(algorithm))
+        .and_then(|value| value.strip_prefix('='))
+        .ok_or(WebhookError::MalformedSignature)?;
+
+    if hex.len() % 2 != 0 {
+        return Err(WebhookError::MalformedSignature);
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or(WebhookError::MalformedSignature)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example taken from GitHub's "Validating webhook deliveries" documentation.
+    const SECRET: &str = "It's a Secret to Everybody";
+    const BODY: &[u8] = b"Hello, World!";
+    const SHA256: &str = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+    const SHA1: &str = "sha1=01dc10d0c83e72ed246219cdd91669667fe2ca59";
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn accepts_valid_sha256_signature() {
+        let verifier = WebhookVerifier::new(SECRET);
+        assert_eq!(
+            verifier.verify(&headers(&[(SIGNATURE_256_HEADER, SHA256)]), BODY),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_body() {
+        let verifier = WebhookVerifier::new(SECRET);
+        assert_eq!(
+            verifier.verify(
+                &headers(&[(SIGNATURE_256_HEADER, SHA256)]),
+                b"Hello, World?"
+            ),
+            Err(WebhookError::SignatureMismatch)
+        );
+    }
+
+    #[test]
+    fn rejects_missing_and_malformed_signatures() {
+        let verifier = WebhookVerifier::new(SECRET);
+        assert_eq!(
+            verifier.verify(&HeaderMap::new(), BODY),
+            Err(WebhookError::MissingHeader(SIGNATURE_256_HEADER))
+        );
+        assert_eq!(
+            verifier.verify(&headers(&[(SIGNATURE_256_HEADER, "sha256=zz")]), BODY),
+            Err(WebhookError::MalformedSignature)
+        );
+        assert_eq!(
+            verifier.verify(&headers(&[(SIGNATURE_256_HEADER, &SHA256[7..])]), BODY),
+            Err(WebhookError::MalformedSignature)
+        );
+    }
+
+    #[test]
+    fn accepts_any_rotating_secret() {
+        let verifier = WebhookVerifier::new("new secret").add_secret(SECRET);
+        assert_eq!(
+            verifier.verify(&headers(&[(SIGNATURE_256_HEADER, SHA256)]), BODY),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn legacy_sha1_requires_opt_in() {
+        let sha1_only = headers(&[(SIGNATURE_HEADER, SHA1)]);
+        assert_eq!(
+            WebhookVerifier::new(SECRET).verify(&sha1_only, BODY),
+            Err(WebhookError::MissingHeader(SIGNATURE_256_HEADER))
+        );
+        assert_eq!(
+            WebhookVerifier::new(SECRET)
+                .allow_legacy_sha1(true)
+                .verify(&sha1_only, BODY),
+            Ok(())
+        );
+    }
+}
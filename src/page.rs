@@ -2,21 +2,23 @@ use bytes::Bytes;
 use http::Uri;
 use http_body::Body;
 use http_body_util::BodyExt;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::slice::Iter;
 use std::str::FromStr;
 
+use serde::de::DeserializeOwned;
+
 use crate::error::{SerdeSnafu, UriSnafu};
+use crate::Octocrab;
 use snafu::ResultExt;
 use url::form_urlencoded;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "stream")] {
-        use futures_core::Stream;
-        use futures_util::stream::try_unfold;
-        use serde::de::DeserializeOwned;
+        use std::borrow::Borrow;
 
-        use crate::Octocrab;
+        use futures_core::Stream;
+        use futures_util::stream::{try_unfold, BoxStream, StreamExt};
     }
 }
 
@@ -56,9 +58,68 @@ where
     Option::<String>::serialize(&uri.as_ref().map(Uri::to_string), serializer)
 }
 
+/// The position of a paginated listing, which can be persisted, e.g. as
+/// JSON, and later resumed with [`Octocrab::resume`], even on another client.
+///
+/// It is the link to the next page, which keeps the query parameters of the
+/// builder which sent the first request, such as filters and `per_page`.
+///
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// # let saved = String::new();
+/// use octocrab::{models::issues::Issue, Page, PageCursor};
+///
+/// let octocrab = octocrab::instance();
+/// let mut page: Page<Issue> = match serde_json::from_str::<PageCursor>(&saved) {
+///     Ok(cursor) => octocrab.resume(&cursor).await?,
+///     Err(_) => octocrab.issues("rust-lang", "rust").list().per_page(100).send().await?,
+/// };
+/// loop {
+///     for issue in page.take_items() {
+///         println!("{}", issue.title);
+///     }
+///     let cursor = match page.cursor() {
+///         Some(cursor) => cursor,
+///         None => break,
+///     };
+///     // Persist `serde_json::to_string(&cursor)` to continue from here later.
+///     page = octocrab.resume(&cursor).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+    #[serde(serialize_with = "serialize_uri", deserialize_with = "deserialize_uri")]
+    next: Uri,
+}
+
+impl PageCursor {
+    /// Returns the link to the next page.
+    pub fn next(&self) -> &Uri {
+        &self.next
+    }
+}
+
+fn serialize_uri<S>(uri: &Uri, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&uri.to_string())
+}
+
+fn deserialize_uri<'de, D>(deserializer: D) -> Result<Uri, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
 #[cfg(feature = "stream")]
-struct PageIterator<'octo, T> {
-    crab: &'octo Octocrab,
+struct PageIterator<C, T> {
+    crab: C,
     next: Option<Uri>,
     current: std::vec::IntoIter<T>,
 }
@@ -69,20 +130,36 @@ impl<T> Page<T> {
         std::mem::take(&mut self.items)
     }
 
+    /// Returns the cursor to resume the listing from the next page, or `None`
+    /// if this is the last page.
+    pub fn cursor(&self) -> Option<PageCursor> {
+        self.next.clone().map(|next| PageCursor { next })
+    }
+
     /// If `last` is present, return the number of pages for this navigation.
     pub fn number_of_pages(&self) -> Option<u32> {
-        self.last.as_ref().and_then(|uri| {
-            let query = form_urlencoded::parse(uri.query().unwrap_or("").as_bytes());
-            query
-                .filter_map(|(k, v)| {
-                    if k == "page" {
-                        Some(v).and_then(|v| v.parse().ok())
-                    } else {
-                        None
-                    }
-                })
-                .next()
-        })
+        self.last.as_ref().and_then(page_number)
+    }
+
+    /// Returns the links to every page after this one, built from the `next`
+    /// and `last` links, or `None` if there is a next page but no `last` link.
+    pub(crate) fn remaining_pages(&self) -> crate::Result<Option<Vec<Uri>>> {
+        let next = match &self.next {
+            Some(next) => next,
+            None => return Ok(Some(Vec::new())),
+        };
+        let (first, last) = match (page_number(next), self.last.as_ref()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Ok(None),
+        };
+        let last_page = match page_number(last) {
+            Some(last_page) => last_page,
+            None => return Ok(None),
+        };
+        (first..=last_page)
+            .map(|page| with_page_number(last, page))
+            .collect::<crate::Result<_>>()
+            .map(Some)
     }
 
     /// Convert Page into a stream of results
@@ -116,6 +193,17 @@ impl<T> Page<T> {
     pub fn into_stream(self, crab: &Octocrab) -> impl Stream<Item = crate::Result<T>> + '_
     where
         T: DeserializeOwned + 'static,
+    {
+        self.stream_with(crab)
+    }
+
+    /// Like [`Page::into_stream`], for any client handle, e.g. an owned
+    /// [`Octocrab`] so that the stream doesn't borrow it.
+    #[cfg(feature = "stream")]
+    fn stream_with<C>(self, crab: C) -> impl Stream<Item = crate::Result<T>>
+    where
+        C: Borrow<Octocrab>,
+        T: DeserializeOwned + 'static,
     {
         let state = PageIterator {
             crab,
@@ -126,7 +214,7 @@ impl<T> Page<T> {
             if let Some(val) = state.current.next() {
                 return Ok(Some((val, state)));
             }
-            let page = state.crab.get_page::<T>(&state.next).await?;
+            let page = state.crab.borrow().get_page::<T>(&state.next).await?;
             Ok(page.and_then(|page| {
                 let mut current = page.items.into_iter();
                 // If we get an empty page we'll return early here with out
@@ -145,6 +233,190 @@ impl<T> Page<T> {
     }
 }
 
+/// A request for a list of results spread over several [`Page`]s, such as
+/// the `List*Builder`s, which can be sent to get the results of every page.
+///
+/// Responses which wrap their items in an object with a `total_count`, like
+/// the lists of check runs or workflow runs, are paginated the same way as
+/// plain arrays.
+///
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// use octocrab::Paginate;
+///
+/// let octocrab = octocrab::instance();
+///
+/// let issues = octocrab
+///     .issues("rust-lang", "rust")
+///     .list()
+///     .per_page(100)
+///     .send_all()
+///     .await?;
+///
+/// // Only fetches as many pages as needed for the first 250 runs.
+/// let runs = octocrab
+///     .workflows("rust-lang", "rust")
+///     .list_all_runs()
+///     .per_page(100)
+///     .send_at_most(250)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait Paginate: Sized + Send {
+    /// The type of the listed items.
+    type Item: DeserializeOwned + Send + 'static;
+
+    /// Returns the client sending the requests.
+    fn crab(&self) -> &Octocrab;
+
+    /// Sends the request for the first page.
+    async fn first_page(self) -> crate::Result<Page<Self::Item>>;
+
+    /// Sends the request, then follows the `next` links to return the items
+    /// of every page.
+    async fn send_all(self) -> crate::Result<Vec<Self::Item>> {
+        let crab = self.crab().clone();
+        let page = self.first_page().await?;
+        crab.all_pages(page).await
+    }
+
+    /// Sends the request, then fetches the remaining pages up to
+    /// `max_concurrent` at a time, returning the items of every page in
+    /// order. See [`Octocrab::all_pages_concurrent`].
+    async fn send_all_concurrent(self, max_concurrent: usize) -> crate::Result<Vec<Self::Item>> {
+        let crab = self.crab().clone();
+        let page = self.first_page().await?;
+        crab.all_pages_concurrent(page, max_concurrent).await
+    }
+
+    /// Sends the request, then follows the `next` links until `max_items`
+    /// items were received, returning at most `max_items` items.
+    async fn send_at_most(self, max_items: usize) -> crate::Result<Vec<Self::Item>> {
+        let crab = self.crab().clone();
+        let mut page = self.first_page().await?;
+        let mut items = page.take_items();
+        while items.len() < max_items {
+            match crab.get_page(&page.next).await? {
+                Some(mut next_page) => {
+                    items.append(&mut next_page.take_items());
+                    page = next_page;
+                }
+                None => break,
+            }
+        }
+        items.truncate(max_items);
+        Ok(items)
+    }
+
+    /// Sends the request for the first page, then returns a stream of the
+    /// items of every page, fetching the next page as the stream is polled.
+    ///
+    /// The stream doesn't borrow the builder or the client, and only fetches
+    /// as many pages as are consumed, so it can be capped with
+    /// [`take`](futures_util::StreamExt::take).
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use futures_util::{StreamExt, TryStreamExt};
+    /// use octocrab::Paginate;
+    ///
+    /// let mut check_runs = octocrab::instance()
+    ///     .checks("owner", "repo")
+    ///     .list_check_runs_for_git_ref("main".to_string().into())
+    ///     .stream()
+    ///     .await?
+    ///     .take(500);
+    /// while let Some(check_run) = check_runs.try_next().await? {
+    ///     println!("{}", check_run.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    async fn stream(self) -> crate::Result<BoxStream<'static, crate::Result<Self::Item>>> {
+        let crab = self.crab().clone();
+        let page = self.first_page().await?;
+        Ok(page.stream_with(crab).boxed())
+    }
+}
+
+/// Implements [`Paginate`] for a list builder, given how to get its client
+/// from the builder. The first page is sent with the builder's `send`
+/// method, unless a closure sending it is also given. The generic parameters
+/// of the impl, if any, go in leading brackets.
+///
+/// ```ignore
+/// impl_paginate! { ListTagsBuilder<'_, '_>, models::repos::Tag, |b| b.handler.crab }
+/// ```
+macro_rules! impl_paginate {
+    ([$($generics:tt)*] $builder:ty, $item:ty, |$this:ident| $crab:expr, |$sent:ident| $first_page:expr) => {
+        #[async_trait::async_trait]
+        impl<$($generics)*> $crate::Paginate for $builder {
+            type Item = $item;
+
+            fn crab(&self) -> &$crate::Octocrab {
+                let $this = self;
+                $crab
+            }
+
+            async fn first_page(self) -> $crate::Result<$crate::Page<Self::Item>> {
+                let $sent = self;
+                $first_page
+            }
+        }
+    };
+    ([$($generics:tt)*] $builder:ty, $item:ty, |$this:ident| $crab:expr) => {
+        $crate::page::impl_paginate!(
+            [$($generics)*] $builder,
+            $item,
+            |$this| $crab,
+            |builder| builder.send().await
+        );
+    };
+    ($builder:ty, $($rest:tt)*) => {
+        $crate::page::impl_paginate!([] $builder, $($rest)*);
+    };
+}
+pub(crate) use impl_paginate;
+
+/// Returns the `page` query parameter of a pagination link.
+fn page_number(uri: &Uri) -> Option<u32> {
+    form_urlencoded::parse(uri.query().unwrap_or("").as_bytes())
+        .filter(|(k, _)| k == "page")
+        .find_map(|(_, v)| v.parse().ok())
+}
+
+/// Returns the pagination link `uri` with its `page` query parameter set to
+/// `page`.
+fn with_page_number(uri: &Uri, page: u32) -> crate::Result<Uri> {
+    let page = page.to_string();
+    let query = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            form_urlencoded::parse(uri.query().unwrap_or("").as_bytes()).map(|(k, v)| {
+                if k == "page" {
+                    (k, page.as_str().into())
+                } else {
+                    (k, v)
+                }
+            }),
+        )
+        .finish();
+
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = Some(
+        format!("{}?{}", uri.path(), query)
+            .parse()
+            .context(UriSnafu)?,
+    );
+    Uri::from_parts(parts).map_err(|err| crate::Error::Other {
+        source: Box::new(err),
+        backtrace: snafu::Backtrace::capture(),
+    })
+}
+
 impl<T> Default for Page<T> {
     fn default() -> Self {
         Self {
@@ -214,6 +486,10 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
                 "repositories",
                 "installations",
                 "runners",
+                "check_runs",
+                "check_suites",
+                "environments",
+                "branch_policies",
             ]
             .into_iter()
             .find(|v| json.get(v).is_some())
@@ -377,6 +653,41 @@ mod test {
         assert_eq!(last, None);
     }
 
+    #[test]
+    fn remaining_pages_are_built_from_next_and_last_links() {
+        let page = Page::<()> {
+            next: Some(
+                Uri::from_str("https://api.github.com/orgs/org/repos?per_page=100&page=2").unwrap(),
+            ),
+            last: Some(
+                Uri::from_str("https://api.github.com/orgs/org/repos?per_page=100&page=4").unwrap(),
+            ),
+            ..Page::default()
+        };
+
+        let remaining = page.remaining_pages().expect("No error").unwrap();
+        assert_eq!(
+            remaining,
+            vec![
+                Uri::from_str("https://api.github.com/orgs/org/repos?per_page=100&page=2").unwrap(),
+                Uri::from_str("https://api.github.com/orgs/org/repos?per_page=100&page=3").unwrap(),
+                Uri::from_str("https://api.github.com/orgs/org/repos?per_page=100&page=4").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn remaining_pages_need_last_link() {
+        let page = Page::<()> {
+            next: Some(Uri::from_str("https://api.github.com/orgs/org/repos?page=2").unwrap()),
+            ..Page::default()
+        };
+        assert_eq!(page.remaining_pages().expect("No error"), None);
+
+        let page = Page::<()>::default();
+        assert_eq!(page.remaining_pages().expect("No error"), Some(Vec::new()));
+    }
+
     #[test]
     fn serialize_page() {
         let page = Page {
@@ -556,6 +556,32 @@ pub mod repos {
     }
 }
 
+pub mod rulesets {
+    //! Parameter types for the rulesets API.
+
+    /// The period of the rule suites to list, counting back from now.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum TimePeriod {
+        Hour,
+        Day,
+        Week,
+        Month,
+    }
+
+    /// The result of the rule suites to list.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum RuleSuiteResult {
+        Pass,
+        Fail,
+        Bypass,
+        All,
+    }
+}
+
 pub mod teams {
     #[derive(Debug, Clone, Copy, serde::Serialize)]
     #[serde(rename_all = "snake_case")]
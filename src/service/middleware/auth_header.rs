@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use http::{header::AUTHORIZATION, request::Request, HeaderValue, Uri};
+use http::{header::AUTHORIZATION, request::Request, uri::Authority, HeaderValue, Uri};
 use tower::{Layer, Service};
 
 #[derive(Clone)]
@@ -9,6 +9,7 @@ pub struct AuthHeaderLayer {
     pub(crate) auth_header: Arc<Option<HeaderValue>>,
     base_uri: Uri,
     upload_uri: Uri,
+    trusted_hosts: Arc<[String]>,
 }
 
 impl AuthHeaderLayer {
@@ -17,8 +18,25 @@ impl AuthHeaderLayer {
             auth_header: Arc::new(auth_header),
             base_uri,
             upload_uri,
+            trusted_hosts: Arc::new([]),
         }
     }
+
+    /// Also sends the header to these hosts, see [is_trusted_authority].
+    pub fn with_trusted_hosts(mut self, trusted_hosts: impl IntoIterator<Item = String>) -> Self {
+        self.trusted_hosts = trusted_hosts.into_iter().collect();
+        self
+    }
+}
+
+/// Returns whether credentials may be sent to `authority`, i.e. whether it is
+/// equal to one of `trusted_hosts`, or has the same host as one of them which
+/// has no port.
+pub fn is_trusted_authority(authority: &Authority, trusted_hosts: &[String]) -> bool {
+    trusted_hosts.iter().any(|trusted| {
+        trusted.eq_ignore_ascii_case(authority.as_str())
+            || (!trusted.contains(':') && trusted.eq_ignore_ascii_case(authority.host()))
+    })
 }
 
 impl<S> Layer<S> for AuthHeaderLayer {
@@ -30,6 +48,7 @@ impl<S> Layer<S> for AuthHeaderLayer {
             auth_header: self.auth_header.clone(),
             base_uri: self.base_uri.clone(),
             upload_uri: self.upload_uri.clone(),
+            trusted_hosts: self.trusted_hosts.clone(),
         }
     }
 }
@@ -41,6 +60,7 @@ pub struct AuthHeader<S> {
     pub(crate) auth_header: Arc<Option<HeaderValue>>,
     base_uri: Uri,
     upload_uri: Uri,
+    trusted_hosts: Arc<[String]>,
 }
 
 impl<S, ReqBody> Service<Request<ReqBody>> for AuthHeader<S>
@@ -65,7 +85,14 @@ where
         // want to give our credentials to third-party services.
         let authority = req.uri().authority();
         let allowed_authorities = [self.base_uri.authority(), self.upload_uri.authority()];
-        if authority.is_none() || allowed_authorities.contains(&authority) {
+        let trusted = match authority {
+            None => true,
+            Some(authority) => {
+                allowed_authorities.contains(&Some(authority))
+                    || is_trusted_authority(authority, &self.trusted_hosts)
+            }
+        };
+        if trusted {
             if let Some(auth_header) = &*self.auth_header {
                 req.headers_mut().append(AUTHORIZATION, auth_header.clone());
             }
@@ -63,25 +63,19 @@ where
     }
 }
 
-// Join base URI and Path+Query, preserving any path in the base.
+// Join base URI and Path+Query, preserving any path in the base. Absolute
+// URIs, e.g. the GraphQL endpoint of GitHub Enterprise Server, are left as is.
 fn overwrite_base_uri(base_uri: &http::Uri, current_uri: Uri) -> http::Uri {
+    if current_uri.authority().is_some() {
+        return current_uri;
+    }
     let req_pandq = current_uri.path_and_query();
     let mut builder = uri::Builder::new();
-    match current_uri.scheme() {
-        Some(scheme) => builder = builder.scheme(scheme.as_str()),
-        None => {
-            if let Some(scheme) = base_uri.scheme() {
-                builder = builder.scheme(scheme.as_str());
-            }
-        }
+    if let Some(scheme) = base_uri.scheme() {
+        builder = builder.scheme(scheme.as_str());
     }
-    match current_uri.authority() {
-        Some(authority) => builder = builder.authority(authority.as_str()),
-        None => {
-            if let Some(authority) = base_uri.authority() {
-                builder = builder.authority(authority.as_str());
-            }
-        }
+    if let Some(authority) = base_uri.authority() {
+        builder = builder.authority(authority.as_str());
     }
 
     if let Some(pandq) = base_uri.path_and_query() {
@@ -127,4 +121,14 @@ mod tests {
             "https://example.com/foo/bar/api/v1/nodes?hi=yes"
         );
     }
+
+    #[test]
+    fn absolute_uri() {
+        let base_uri = http::Uri::from_static("https://ghe.example.com/api/v3");
+        let graphql = http::Uri::from_static("https://ghe.example.com/api/graphql");
+        assert_eq!(
+            super::overwrite_base_uri(&base_uri, graphql),
+            "https://ghe.example.com/api/graphql"
+        );
+    }
 }
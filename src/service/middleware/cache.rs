@@ -1,3 +1,4 @@
+pub mod fs;
 pub mod mem;
 
 use std::{
@@ -25,6 +26,67 @@ pub enum CacheKey {
     LastModified(String),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Identity of the credentials a request is made with.
+///
+/// Cached responses are partitioned by identity, so that a response fetched
+/// with one set of credentials is never served to a request made with another.
+/// It is derived from the authentication state of [`crate::Octocrab`] (e.g.
+/// the installation ID), or from a digest of the `Authorization` header; the
+/// credentials themselves are never part of the identity.
+pub struct CacheIdentity(String);
+
+impl CacheIdentity {
+    /// Creates an identity from an opaque string.
+    pub fn new(identity: impl Into<String>) -> Self {
+        Self(identity.into())
+    }
+
+    /// Returns the opaque string representation of the identity.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the identity of `req`: the [CacheIdentity] request extension if
+    /// present, otherwise a digest of its `Authorization` header.
+    pub(crate) fn from_request<B>(req: &Request<B>) -> Option<Self> {
+        req.extensions()
+            .get::<CacheIdentity>()
+            .cloned()
+            .or_else(|| {
+                req.headers()
+                    .get(header::AUTHORIZATION)
+                    .map(Self::from_authorization)
+            })
+    }
+
+    fn from_authorization(value: &HeaderValue) -> Self {
+        Self(format!("sha256:{}", hex_digest(value.as_bytes())))
+    }
+
+    /// Returns the URI the responses to `uri` are stored under for this
+    /// identity.
+    fn partition(&self, uri: &Uri) -> Uri {
+        let path_and_query = uri.path_and_query().map_or("/", |pq| pq.as_str());
+        let mut parts = uri.clone().into_parts();
+        parts.path_and_query = Some(
+            format!("/~{}{path_and_query}", hex_digest(self.0.as_bytes()))
+                .parse()
+                .expect("hex digest followed by a path is a valid path"),
+        );
+        Uri::from_parts(parts).expect("only the path of a valid URI was changed")
+    }
+}
+
+fn hex_digest(data: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
 #[derive(Debug, Clone, Default)]
 /// Cache entry containing the response data as well as response headers.
 pub struct CachedResponse {
@@ -34,6 +96,10 @@ pub struct CachedResponse {
 
 /// [HttpCacheLayer] is agnostic to the storage implementation (e.g., in-memory,
 /// filesystem, etc.). This trait represents the required interface.
+///
+/// The layer partitions the entries by the [CacheIdentity] of the requests:
+/// the URI of an authenticated request is prefixed with a digest of its
+/// identity, so storages should treat URIs as opaque keys.
 pub trait CacheStorage: Send + Sync {
     /// Returns the stored cache key for given URI if it's available in the
     /// cache.
@@ -53,6 +119,10 @@ pub trait CacheStorage: Send + Sync {
 /// Writes the response body to the cache.
 pub trait CacheWriter: Send + Sync {
     fn write_body(&mut self, data: &[u8]);
+
+    /// Called once the whole body was written. Writers dropped without it got
+    /// an incomplete body, e.g. because reading the response failed.
+    fn finish(&mut self) {}
 }
 
 #[derive(Clone)]
@@ -101,12 +171,19 @@ where
     }
 
     fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
-        let uri = req.uri().clone();
+        let uri = match CacheIdentity::from_request(&req) {
+            Some(identity) => identity.partition(req.uri()),
+            None => req.uri().clone(),
+        };
+        let mut cached = None;
 
         if let Some(ref storage) = self.storage {
             // If there is a cache record for this URI, add the corresponding
             // header so that GitHub API might send the unmodified response.
+            // The cached response is loaded while the request is in flight,
+            // as the storage may evict it in the meantime.
             if let Some(key) = storage.try_hit(&uri) {
+                cached = Cached::load(storage.clone(), uri.clone());
                 match key {
                     CacheKey::ETag(etag) => {
                         req.headers_mut()
@@ -126,7 +203,51 @@ where
             inner: self.inner.call(req),
             storage: self.storage.clone(),
             uri,
+            cached,
+            response: None,
+        }
+    }
+}
+
+/// The cached response to reuse if GitHub answers that it was not modified.
+enum Cached {
+    Loaded(CachedResponse),
+    #[cfg(feature = "tokio")]
+    Loading(tokio::task::JoinHandle<Option<CachedResponse>>),
+}
+
+impl Cached {
+    fn load(storage: Arc<dyn CacheStorage>, uri: Uri) -> Option<Self> {
+        // Storages may block on IO (e.g. the file system), so keep them off
+        // the threads driving the requests when running on tokio.
+        #[cfg(feature = "tokio")]
+        {
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                return Some(Cached::Loading(
+                    runtime.spawn_blocking(move || storage.load(&uri)),
+                ));
+            }
         }
+        storage.load(&uri).map(Cached::Loaded)
+    }
+
+    #[cfg_attr(not(feature = "tokio"), allow(unused_variables))]
+    fn poll_take(
+        cached: &mut Option<Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<CachedResponse>> {
+        #[cfg(feature = "tokio")]
+        {
+            if let Some(Cached::Loading(handle)) = cached {
+                let response = ready!(Pin::new(handle).poll(cx)).ok().flatten();
+                *cached = None;
+                return Poll::Ready(response);
+            }
+        }
+        Poll::Ready(match cached.take() {
+            Some(Cached::Loaded(response)) => Some(response),
+            _ => None,
+        })
     }
 }
 
@@ -136,6 +257,8 @@ pub struct HttpCacheFuture<F> {
     inner: F,
     storage: Option<Arc<dyn CacheStorage>>,
     uri: Uri,
+    cached: Option<Cached>,
+    response: Option<Response<ResBody>>,
 }
 
 impl<F, E> Future for HttpCacheFuture<F>
@@ -149,28 +272,43 @@ where
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Self::Output> {
         let this = self.project();
-        let mut response = ready!(this.inner.poll(cx))?;
+        let mut response = match this.response.take() {
+            Some(response) => response,
+            None => ready!(this.inner.poll(cx))?,
+        };
 
         if let Some(ref storage) = this.storage {
             if response.status() == StatusCode::NOT_MODIFIED {
+                let cached = match Cached::poll_take(this.cached, cx) {
+                    Poll::Ready(cached) => cached,
+                    Poll::Pending => {
+                        *this.response = Some(response);
+                        return Poll::Pending;
+                    }
+                };
+
                 // If the response is indicated as not modified, reuse the body
-                // from the cache.
-                let cached = storage.load(this.uri).expect("no body for cache hit");
-
-                for (name, value) in cached.headers.iter() {
-                    // These headers are missing in the HTTP 304 Not Modified
-                    // response from GitHub API, but are important for further
-                    // processing.
-                    if [header::CONTENT_TYPE, header::CONTENT_LENGTH, header::LINK].contains(name) {
-                        response.headers_mut().append(name, value.clone());
+                // from the cache. The entry may have been removed meanwhile
+                // (e.g. by another process), then the response is left as is.
+                if let Some(cached) = cached {
+                    for (name, value) in cached.headers.iter() {
+                        // These headers are missing in the HTTP 304 Not
+                        // Modified response from GitHub API, but are important
+                        // for further processing.
+                        if [header::CONTENT_TYPE, header::CONTENT_LENGTH, header::LINK]
+                            .contains(name)
+                        {
+                            response.headers_mut().append(name, value.clone());
+                        }
                     }
-                }
 
-                // Replace the body.
-                *response.body_mut() = BoxBody::new(Box::new(
-                    Full::new(Bytes::from(cached.body)).map_err(|infallible| match infallible {}),
-                ));
-                *response.status_mut() = StatusCode::OK;
+                    // Replace the body.
+                    *response.body_mut() = BoxBody::new(Box::new(
+                        Full::new(Bytes::from(cached.body))
+                            .map_err(|infallible| match infallible {}),
+                    ));
+                    *response.status_mut() = StatusCode::OK;
+                }
             } else {
                 // Try to extract a cache header (either ETag or Last-Modified).
                 let cache_key = CacheKey::extract_from_headers(response.headers());
@@ -195,11 +333,16 @@ struct WriteToCacheBody<B> {
     #[pin]
     inner: B,
     writer: Box<dyn CacheWriter>,
+    failed: bool,
 }
 
 impl<B> WriteToCacheBody<B> {
     fn new(inner: B, writer: Box<dyn CacheWriter>) -> Self {
-        Self { inner, writer }
+        Self {
+            inner,
+            writer,
+            failed: false,
+        }
     }
 }
 
@@ -217,10 +360,16 @@ where
         let this = self.project();
         match this.inner.poll_frame(cx) {
             Poll::Ready(frame) => {
-                if let Some(Ok(ref data)) = frame {
-                    if let Some(data) = data.data_ref() {
-                        this.writer.write_body(data);
+                match frame {
+                    Some(Ok(ref data)) => {
+                        if let Some(data) = data.data_ref() {
+                            this.writer.write_body(data);
+                        }
                     }
+                    Some(Err(_)) => *this.failed = true,
+                    // Only a body read to the end without errors is complete.
+                    None if !*this.failed => this.writer.finish(),
+                    None => {}
                 }
 
                 Poll::Ready(frame)
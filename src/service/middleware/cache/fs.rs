@@ -0,0 +1,594 @@
+//! A [CacheStorage] persisting responses to a directory, so that conditional
+//! requests keep saving rate limit across process runs.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use octocrab::service::middleware::cache::fs::FileSystemCache;
+//!
+//! let cache = FileSystemCache::new("/var/cache/my-tool/github")?
+//!     .max_entries(10_000)
+//!     .max_bytes(256 * 1024 * 1024);
+//! let octocrab = octocrab::Octocrab::builder().cache(cache).build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Each entry is stored in its own file, written to a temporary file first and
+//! then atomically renamed into place. When a limit is exceeded, the least
+//! recently used entries are removed. When running on tokio, the files are
+//! written on its blocking threads. As responses may be private, on unix the
+//! directory and the files are only accessible by their owner.
+
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime},
+};
+
+use http::{header::HeaderName, HeaderMap, HeaderValue, Uri};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{CacheKey, CacheStorage, CacheWriter, CachedResponse};
+
+const ENTRY_EXTENSION: &str = "entry";
+const FORMAT_VERSION: u32 = 1;
+const TEMP_FILE_PREFIX: &str = ".tmp-";
+/// Temporary files older than this were left by a process that crashed while
+/// writing them.
+const STALE_TEMP_FILE_AGE: Duration = Duration::from_secs(60 * 60);
+
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// [CacheStorage] keeping responses in files under a directory.
+pub struct FileSystemCache {
+    inner: Arc<Mutex<CacheIndex>>,
+}
+
+impl FileSystemCache {
+    /// Opens (and creates if needed) a cache stored in `dir`, indexing the
+    /// entries left by previous runs.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        let mut builder = fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder.create(&dir)?;
+
+        let mut found = Vec::new();
+        for dir_entry in fs::read_dir(&dir)? {
+            let path = dir_entry?.path();
+            if is_stale_temp_file(&path) {
+                let _ = fs::remove_file(&path);
+                continue;
+            }
+            if path.extension().and_then(|ext| ext.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let Ok(file) = File::open(&path) else {
+                continue;
+            };
+            let metadata = file.metadata()?;
+            match read_meta(&mut BufReader::new(file)) {
+                Ok(meta) => {
+                    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    found.push((name.to_owned(), meta.key(), metadata.len(), modified));
+                }
+                // Unreadable or outdated entry, it would never be used.
+                Err(_) => {
+                    let _ = fs::remove_file(&path);
+                }
+            }
+        }
+
+        // Files used most recently were touched last.
+        found.sort_by_key(|(_, _, _, modified)| *modified);
+
+        let mut index = CacheIndex {
+            dir,
+            entries: HashMap::new(),
+            total_bytes: 0,
+            max_entries: None,
+            max_bytes: None,
+            clock: 0,
+        };
+        for (name, key, size, _) in found {
+            if let Some(key) = key {
+                index.clock += 1;
+                index.total_bytes += size;
+                index.entries.insert(
+                    name,
+                    IndexEntry {
+                        key,
+                        size,
+                        last_used: index.clock,
+                    },
+                );
+            }
+        }
+
+        Ok(Self {
+            inner: Arc::new(Mutex::new(index)),
+        })
+    }
+
+    /// Limits the number of cached responses.
+    pub fn max_entries(self, max_entries: usize) -> Self {
+        let evicted = {
+            let mut index = self.inner.lock().unwrap();
+            index.max_entries = Some(max_entries);
+            index.evict()
+        };
+        remove_files(evicted);
+        self
+    }
+
+    /// Limits the total size of the cache files, in bytes.
+    pub fn max_bytes(self, max_bytes: u64) -> Self {
+        let evicted = {
+            let mut index = self.inner.lock().unwrap();
+            index.max_bytes = Some(max_bytes);
+            index.evict()
+        };
+        remove_files(evicted);
+        self
+    }
+
+    /// Returns the directory the cache is stored in.
+    pub fn dir(&self) -> PathBuf {
+        self.inner.lock().unwrap().dir.clone()
+    }
+}
+
+struct CacheIndex {
+    dir: PathBuf,
+    entries: HashMap<String, IndexEntry>,
+    total_bytes: u64,
+    max_entries: Option<usize>,
+    max_bytes: Option<u64>,
+    clock: u64,
+}
+
+struct IndexEntry {
+    key: CacheKey,
+    size: u64,
+    last_used: u64,
+}
+
+impl CacheIndex {
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{name}.{ENTRY_EXTENSION}"))
+    }
+
+    fn touch(&mut self, name: &str) {
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(name) {
+            entry.last_used = self.clock;
+        }
+    }
+
+    /// Removes the entry from the index, returning the path of its file.
+    fn remove(&mut self, name: &str) -> Option<PathBuf> {
+        let entry = self.entries.remove(name)?;
+        self.total_bytes -= entry.size;
+        Some(self.path(name))
+    }
+
+    fn is_over_limits(&self) -> bool {
+        self.max_entries
+            .is_some_and(|max_entries| self.entries.len() > max_entries)
+            || self
+                .max_bytes
+                .is_some_and(|max_bytes| self.total_bytes > max_bytes)
+    }
+
+    /// Removes the least recently used entries until the limits are satisfied,
+    /// returning the paths of their files.
+    fn evict(&mut self) -> Vec<PathBuf> {
+        let mut evicted = Vec::new();
+        while self.is_over_limits() {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(name, _)| name.clone())
+            else {
+                break;
+            };
+            evicted.extend(self.remove(&oldest));
+        }
+        evicted
+    }
+
+    /// Indexes an entry whose file was written, returning the paths of the
+    /// files of the entries evicted to make room for it.
+    fn insert(&mut self, name: String, key: CacheKey, size: u64) -> Vec<PathBuf> {
+        if let Some(entry) = self.entries.remove(&name) {
+            self.total_bytes -= entry.size;
+        }
+        self.clock += 1;
+        self.total_bytes += size;
+        self.entries.insert(
+            name,
+            IndexEntry {
+                key,
+                size,
+                last_used: self.clock,
+            },
+        );
+        self.evict()
+    }
+}
+
+fn remove_files(paths: Vec<PathBuf>) {
+    for path in paths {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// Runs `io` on a blocking thread of the tokio runtime if there is one, so that
+/// writing entries never blocks the threads driving the requests.
+fn spawn_io(io: impl FnOnce() + Send + 'static) {
+    #[cfg(feature = "tokio")]
+    {
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn_blocking(io);
+            return;
+        }
+    }
+    io()
+}
+
+/// First line of an entry file, followed by the raw response body.
+#[derive(Serialize, Deserialize)]
+struct EntryMeta {
+    version: u32,
+    uri: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    headers: Vec<(String, String)>,
+}
+
+impl EntryMeta {
+    fn key(&self) -> Option<CacheKey> {
+        match (&self.etag, &self.last_modified) {
+            (Some(etag), _) => Some(CacheKey::ETag(etag.clone())),
+            (None, Some(last_modified)) => Some(CacheKey::LastModified(last_modified.clone())),
+            (None, None) => None,
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        self.headers
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((
+                    HeaderName::from_bytes(name.as_bytes()).ok()?,
+                    HeaderValue::from_str(value).ok()?,
+                ))
+            })
+            .collect()
+    }
+
+    fn matches(&self, uri: &Uri) -> bool {
+        self.uri == uri.to_string()
+    }
+}
+
+fn entry_name(uri: &Uri) -> String {
+    Sha256::digest(uri.to_string())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn is_stale_temp_file(path: &Path) -> bool {
+    let is_temp = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with(TEMP_FILE_PREFIX));
+    is_temp
+        && fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > STALE_TEMP_FILE_AGE)
+}
+
+fn read_meta(reader: &mut impl BufRead) -> io::Result<EntryMeta> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let meta: EntryMeta = serde_json::from_str(&line)?;
+    if meta.version != FORMAT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unsupported cache entry version",
+        ));
+    }
+    Ok(meta)
+}
+
+fn read_entry(path: &Path) -> io::Result<(EntryMeta, Vec<u8>)> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let meta = read_meta(&mut reader)?;
+    let mut body = Vec::new();
+    reader.read_to_end(&mut body)?;
+    Ok((meta, body))
+}
+
+/// Writes the entry next to its final location, then renames it into place so
+/// that readers (including other processes) never observe a partial entry.
+fn write_atomically(dir: &Path, path: &Path, meta: &EntryMeta, body: &[u8]) -> io::Result<u64> {
+    let temp_path = dir.join(format!(
+        "{TEMP_FILE_PREFIX}{}-{}",
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = (|| {
+        let mut options = File::options();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&temp_path)?;
+        serde_json::to_writer(&mut file, meta)?;
+        file.write_all(b"\n")?;
+        file.write_all(body)?;
+        file.sync_all()?;
+        let size = file.metadata()?.len();
+        fs::rename(&temp_path, path)?;
+        Ok(size)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+struct FileSystemWriter(Option<PendingEntry>);
+
+/// A response being received, written to the cache once complete.
+struct PendingEntry {
+    cache: Arc<Mutex<CacheIndex>>,
+    name: String,
+    key: CacheKey,
+    meta: EntryMeta,
+    body: Vec<u8>,
+}
+
+impl CacheStorage for FileSystemCache {
+    fn try_hit(&self, uri: &Uri) -> Option<CacheKey> {
+        let name = entry_name(uri);
+        let index = self.inner.lock().unwrap();
+        index.entries.get(&name).map(|entry| entry.key.clone())
+    }
+
+    fn load(&self, uri: &Uri) -> Option<CachedResponse> {
+        let name = entry_name(uri);
+        let path = {
+            let mut index = self.inner.lock().unwrap();
+            if !index.entries.contains_key(&name) {
+                return None;
+            }
+            index.touch(&name);
+            index.path(&name)
+        };
+
+        match read_entry(&path) {
+            Ok((meta, body)) if meta.matches(uri) => {
+                // Keep the recency across runs.
+                if let Ok(file) = File::options().append(true).open(&path) {
+                    let _ = file.set_modified(SystemTime::now());
+                }
+                Some(CachedResponse {
+                    body,
+                    headers: meta.headers(),
+                })
+            }
+            // The entry was removed by another process, or is corrupted.
+            _ => {
+                if let Some(path) = self.inner.lock().unwrap().remove(&name) {
+                    let _ = fs::remove_file(path);
+                }
+                None
+            }
+        }
+    }
+
+    fn writer(&self, uri: &Uri, key: CacheKey, headers: HeaderMap) -> Box<dyn CacheWriter> {
+        let (etag, last_modified) = match key {
+            CacheKey::ETag(ref etag) => (Some(etag.clone()), None),
+            CacheKey::LastModified(ref last_modified) => (None, Some(last_modified.clone())),
+        };
+
+        Box::new(FileSystemWriter(Some(PendingEntry {
+            cache: self.inner.clone(),
+            name: entry_name(uri),
+            key,
+            meta: EntryMeta {
+                version: FORMAT_VERSION,
+                uri: uri.to_string(),
+                etag,
+                last_modified,
+                headers: headers
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned()))
+                    })
+                    .collect(),
+            },
+            body: Vec::new(),
+        })))
+    }
+}
+
+impl CacheWriter for FileSystemWriter {
+    fn write_body(&mut self, data: &[u8]) {
+        if let Some(entry) = &mut self.0 {
+            entry.body.extend_from_slice(data);
+        }
+    }
+
+    fn finish(&mut self) {
+        // Writers dropped before are discarded, so that incomplete responses
+        // are never persisted.
+        if let Some(entry) = self.0.take() {
+            spawn_io(move || entry.persist());
+        }
+    }
+}
+
+impl PendingEntry {
+    fn persist(self) {
+        let (dir, path) = {
+            let index = self.cache.lock().unwrap();
+            (index.dir.clone(), index.path(&self.name))
+        };
+        let size = match write_atomically(&dir, &path, &self.meta, &self.body) {
+            Ok(size) => size,
+            Err(_err) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("failed to write cache entry {}: {}", path.display(), _err);
+                return;
+            }
+        };
+
+        let evicted = self.cache.lock().unwrap().insert(self.name, self.key, size);
+        remove_files(evicted);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("octocrab-fs-cache-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn store(cache: &FileSystemCache, uri: &str, body: &[u8]) {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::ETAG, HeaderValue::from_static("\"abc\""));
+        let mut writer = cache.writer(
+            &uri.parse().unwrap(),
+            CacheKey::ETag("\"abc\"".into()),
+            headers,
+        );
+        writer.write_body(body);
+        writer.finish();
+    }
+
+    #[test]
+    fn persists_across_instances() {
+        let dir = temp_dir("persist");
+        let uri: Uri = "/repos/XAMPPRocky/octocrab".parse().unwrap();
+
+        store(
+            &FileSystemCache::new(&dir).unwrap(),
+            "/repos/XAMPPRocky/octocrab",
+            b"{}",
+        );
+
+        let cache = FileSystemCache::new(&dir).unwrap();
+        assert_eq!(cache.try_hit(&uri), Some(CacheKey::ETag("\"abc\"".into())));
+        let response = cache.load(&uri).unwrap();
+        assert_eq!(response.body, b"{}");
+        assert_eq!(response.headers[http::header::ETAG], "\"abc\"");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn discards_unfinished_entries() {
+        let dir = temp_dir("unfinished");
+        let cache = FileSystemCache::new(&dir).unwrap();
+        let uri: Uri = "/a".parse().unwrap();
+
+        let mut writer = cache.writer(&uri, CacheKey::ETag("\"abc\"".into()), HeaderMap::new());
+        writer.write_body(b"{\"trunc");
+        drop(writer);
+
+        assert!(cache.try_hit(&uri).is_none());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn removes_stale_temp_files() {
+        let dir = temp_dir("stale");
+        fs::create_dir_all(&dir).unwrap();
+        let stale = File::create(dir.join(".tmp-1-0")).unwrap();
+        stale
+            .set_modified(SystemTime::now() - 2 * STALE_TEMP_FILE_AGE)
+            .unwrap();
+        File::create(dir.join(".tmp-1-1")).unwrap();
+
+        FileSystemCache::new(&dir).unwrap();
+
+        assert!(!dir.join(".tmp-1-0").exists());
+        assert!(dir.join(".tmp-1-1").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn restricts_permissions_to_owner() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = temp_dir("permissions");
+        let cache = FileSystemCache::new(&dir).unwrap();
+        store(&cache, "/a", b"a");
+
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&dir), 0o700);
+        for entry in fs::read_dir(&dir).unwrap() {
+            assert_eq!(mode(&entry.unwrap().path()), 0o600);
+        }
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let dir = temp_dir("evict");
+        let cache = FileSystemCache::new(&dir).unwrap().max_entries(2);
+
+        store(&cache, "/a", b"a");
+        store(&cache, "/b", b"b");
+        cache.load(&"/a".parse().unwrap()).unwrap();
+        store(&cache, "/c", b"c");
+
+        assert!(cache.try_hit(&"/a".parse().unwrap()).is_some());
+        assert!(cache.try_hit(&"/b".parse().unwrap()).is_none());
+        assert!(cache.try_hit(&"/c".parse().unwrap()).is_some());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        let cache = cache.max_bytes(0);
+        assert!(cache.try_hit(&"/a".parse().unwrap()).is_none());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -5,7 +5,26 @@ use std::{
 
 use super::{CacheKey, CacheStorage, CacheWriter, CachedResponse};
 use http::{HeaderMap, Uri};
+use web_time::{Duration, Instant};
 
+/// Default maximum number of responses kept by [InMemoryCache].
+pub const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+/// In-memory [CacheStorage], bounded by a number of entries (and optionally a
+/// total body size and a time to live).
+///
+/// When a limit is exceeded, the least recently used entries are evicted.
+///
+/// ```
+/// use octocrab::service::middleware::cache::mem::InMemoryCache;
+/// use std::time::Duration;
+///
+/// let cache = InMemoryCache::new()
+///     .max_entries(500)
+///     .max_bytes(64 * 1024 * 1024)
+///     .ttl(Duration::from_secs(60 * 60));
+/// ```
+#[derive(Clone)]
 pub struct InMemoryCache {
     inner: Arc<Mutex<CacheData>>,
 }
@@ -14,11 +33,54 @@ impl InMemoryCache {
     pub fn new() -> Self {
         Self {
             inner: Arc::new(Mutex::new(CacheData {
-                keys: HashMap::new(),
-                responses: HashMap::new(),
+                entries: HashMap::new(),
+                max_entries: Some(DEFAULT_MAX_ENTRIES),
+                max_bytes: None,
+                ttl: None,
+                total_bytes: 0,
+                clock: 0,
+                stats: CacheStats::default(),
             })),
         }
     }
+
+    /// Sets the maximum number of cached responses, `None` for no limit.
+    /// Defaults to [DEFAULT_MAX_ENTRIES].
+    pub fn max_entries(self, max_entries: impl Into<Option<usize>>) -> Self {
+        {
+            let mut cache = self.inner.lock().unwrap();
+            cache.max_entries = max_entries.into();
+            cache.evict();
+        }
+        self
+    }
+
+    /// Sets the maximum total size of the cached bodies, in bytes.
+    pub fn max_bytes(self, max_bytes: usize) -> Self {
+        {
+            let mut cache = self.inner.lock().unwrap();
+            cache.max_bytes = Some(max_bytes);
+            cache.evict();
+        }
+        self
+    }
+
+    /// Discards responses older than `ttl`.
+    pub fn ttl(self, ttl: Duration) -> Self {
+        self.inner.lock().unwrap().ttl = Some(ttl);
+        self
+    }
+
+    /// Returns the hit/miss/eviction counters and the current size of the
+    /// cache.
+    pub fn stats(&self) -> CacheStats {
+        let cache = self.inner.lock().unwrap();
+        CacheStats {
+            entries: cache.entries.len(),
+            bytes: cache.total_bytes,
+            ..cache.stats
+        }
+    }
 }
 
 impl Default for InMemoryCache {
@@ -27,9 +89,119 @@ impl Default for InMemoryCache {
     }
 }
 
+/// Counters describing the activity of an [InMemoryCache].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// Lookups which found a cached response.
+    pub hits: u64,
+    /// Lookups which found no (fresh) cached response.
+    pub misses: u64,
+    /// Responses removed because a limit was exceeded or they expired.
+    pub evictions: u64,
+    /// Number of responses currently cached.
+    pub entries: usize,
+    /// Total size of the bodies currently cached, in bytes.
+    pub bytes: usize,
+}
+
 struct CacheData {
-    keys: HashMap<Uri, CacheKey>,
-    responses: HashMap<Uri, CachedResponse>,
+    entries: HashMap<Uri, CacheEntry>,
+    max_entries: Option<usize>,
+    max_bytes: Option<usize>,
+    ttl: Option<Duration>,
+    total_bytes: usize,
+    clock: u64,
+    stats: CacheStats,
+}
+
+struct CacheEntry {
+    key: CacheKey,
+    response: CachedResponse,
+    inserted_at: Instant,
+    last_used: u64,
+}
+
+impl CacheData {
+    /// Returns the entry for `key` if it exists and did not expire.
+    fn get(&mut self, key: &Uri) -> Option<&mut CacheEntry> {
+        let expired = match (self.entries.get(key), self.ttl) {
+            (None, _) => return None,
+            (Some(entry), Some(ttl)) => entry.inserted_at.elapsed() > ttl,
+            (Some(_), None) => false,
+        };
+        if expired {
+            self.remove(key);
+            self.stats.evictions += 1;
+            return None;
+        }
+
+        self.clock += 1;
+        let clock = self.clock;
+        let entry = self.entries.get_mut(key)?;
+        entry.last_used = clock;
+        Some(entry)
+    }
+
+    fn remove(&mut self, key: &Uri) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.total_bytes -= entry.response.body.len();
+        }
+    }
+
+    fn insert(&mut self, key: Uri, cache_key: CacheKey, response: CachedResponse) {
+        self.remove(&key);
+        self.clock += 1;
+        self.total_bytes += response.body.len();
+        self.entries.insert(
+            key,
+            CacheEntry {
+                key: cache_key,
+                response,
+                inserted_at: Instant::now(),
+                last_used: self.clock,
+            },
+        );
+        self.evict();
+    }
+
+    fn is_over_limits(&self) -> bool {
+        self.max_entries
+            .is_some_and(|max_entries| self.entries.len() > max_entries)
+            || self
+                .max_bytes
+                .is_some_and(|max_bytes| self.total_bytes > max_bytes)
+    }
+
+    /// Drops expired entries, then the least recently used ones until the
+    /// limits are satisfied.
+    fn evict(&mut self) {
+        if let Some(ttl) = self.ttl {
+            let expired: Vec<Uri> = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.inserted_at.elapsed() > ttl)
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in expired {
+                self.remove(&key);
+                self.stats.evictions += 1;
+            }
+        }
+
+        while self.is_over_limits() {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
 }
 
 struct InMemoryWriter {
@@ -41,11 +213,22 @@ struct InMemoryWriter {
 
 impl CacheStorage for InMemoryCache {
     fn try_hit(&self, uri: &Uri) -> Option<CacheKey> {
-        self.inner.lock().unwrap().keys.get(uri).cloned()
+        let mut cache = self.inner.lock().unwrap();
+        let key = cache.get(uri).map(|entry| entry.key.clone());
+        if key.is_some() {
+            cache.stats.hits += 1;
+        } else {
+            cache.stats.misses += 1;
+        }
+        key
     }
 
     fn load(&self, uri: &Uri) -> Option<CachedResponse> {
-        self.inner.lock().unwrap().responses.get(uri).cloned()
+        self.inner
+            .lock()
+            .unwrap()
+            .get(uri)
+            .map(|entry| entry.response.clone())
     }
 
     fn writer(&self, uri: &Uri, key: CacheKey, headers: HeaderMap) -> Box<dyn CacheWriter> {
@@ -76,7 +259,72 @@ impl Drop for InMemoryWriter {
         let response = std::mem::take(&mut self.response);
 
         let mut cache = self.cache.lock().unwrap();
-        cache.keys.insert(uri.clone(), key);
-        cache.responses.insert(uri, response);
+        cache.insert(uri, key, response);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(cache: &InMemoryCache, uri: &str, body: &[u8]) {
+        let mut writer = cache.writer(
+            &uri.parse().unwrap(),
+            CacheKey::ETag("\"abc\"".into()),
+            HeaderMap::new(),
+        );
+        writer.write_body(body);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = InMemoryCache::new().max_entries(2);
+
+        store(&cache, "/a", b"a");
+        store(&cache, "/b", b"b");
+        cache.load(&"/a".parse().unwrap()).unwrap();
+        store(&cache, "/c", b"c");
+
+        assert!(cache.try_hit(&"/a".parse().unwrap()).is_some());
+        assert!(cache.try_hit(&"/b".parse().unwrap()).is_none());
+        assert!(cache.try_hit(&"/c".parse().unwrap()).is_some());
+
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.bytes, stats.evictions), (2, 2, 1));
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let cache = InMemoryCache::new();
+
+        store(&cache, "/a", b"a");
+
+        assert!(cache.try_hit(&"/a".parse().unwrap()).is_some());
+        assert!(cache.try_hit(&"/b".parse().unwrap()).is_none());
+        assert_eq!(cache.load(&"/a".parse().unwrap()).unwrap().body, b"a");
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+    }
+
+    #[test]
+    fn bounded_by_bytes() {
+        let cache = InMemoryCache::new().max_bytes(4);
+
+        store(&cache, "/a", b"aaa");
+        store(&cache, "/b", b"bbb");
+
+        assert!(cache.try_hit(&"/a".parse().unwrap()).is_none());
+        assert_eq!(cache.stats().bytes, 3);
+    }
+
+    #[test]
+    fn expires_entries() {
+        let cache = InMemoryCache::new().ttl(Duration::ZERO);
+
+        store(&cache, "/a", b"a");
+
+        assert!(cache.try_hit(&"/a".parse().unwrap()).is_none());
+        assert_eq!(cache.stats().evictions, 1);
     }
 }
@@ -0,0 +1,444 @@
+//! Proactive rate limit budgeting.
+//!
+//! [RateLimitLayer] records the `x-ratelimit-*` headers of every response and
+//! holds back requests which would exhaust the remaining budget of their
+//! resource (`core`, `search`, `graphql`, `code_search`, ...) until the budget
+//! is reset, instead of waiting for GitHub to answer with a 403/429.
+//!
+//! Budgets are tracked per resource and per identity (see [CacheIdentity]), so
+//! that the installations of an app don't share a budget. A [RateLimitBudget]
+//! can be shared by several clients: each clone keeps its own reserve, which
+//! lets background jobs leave room for interactive traffic.
+//!
+//! ```no_run
+//! # fn main() -> octocrab::Result<()> {
+//! use octocrab::service::middleware::rate_limit::RateLimitBudget;
+//! use std::time::Duration;
+//!
+//! let budget = RateLimitBudget::new();
+//!
+//! let interactive = octocrab::Octocrab::builder()
+//!     .personal_token("token")
+//!     .rate_limit_budget(budget.clone())
+//!     .build()?;
+//!
+//! // Background jobs stop when 20% of a budget is left, and give up instead of
+//! // waiting for more than a minute.
+//! let background = octocrab::Octocrab::builder()
+//!     .personal_token("token")
+//!     .rate_limit_budget(budget.reserve(0.2).max_wait(Duration::from_secs(60)))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use http::{HeaderMap, Request, Response};
+use tower::{BoxError, Layer, Service};
+
+use super::cache::CacheIdentity;
+
+/// A rate limit bucket of the GitHub API.
+///
+/// See <https://docs.github.com/en/rest/rate-limit/rate-limit#about-rate-limits>.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RateLimitResource {
+    Core,
+    Search,
+    Graphql,
+    CodeSearch,
+    Other(String),
+}
+
+impl RateLimitResource {
+    /// Returns the resource a request is expected to be counted against.
+    pub fn of_request<B>(req: &Request<B>) -> Self {
+        let path = req.uri().path();
+        let path = path
+            .strip_prefix("/api/v3")
+            .or_else(|| path.strip_prefix("/api"))
+            .unwrap_or(path);
+
+        if path == "/graphql" {
+            Self::Graphql
+        } else if path.starts_with("/search/code") {
+            Self::CodeSearch
+        } else if path.starts_with("/search/") {
+            Self::Search
+        } else {
+            Self::Core
+        }
+    }
+
+    /// Returns the name of the resource, as found in the
+    /// `x-ratelimit-resource` header.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Core => "core",
+            Self::Search => "search",
+            Self::Graphql => "graphql",
+            Self::CodeSearch => "code_search",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for RateLimitResource {
+    fn from(name: &str) -> Self {
+        match name {
+            "core" => Self::Core,
+            "search" => Self::Search,
+            "graphql" => Self::Graphql,
+            "code_search" => Self::CodeSearch,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for RateLimitResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned instead of sending a request which would exceed the budget
+/// of its resource, when waiting for the reset would take longer than
+/// [RateLimitBudget::max_wait].
+#[derive(Debug, Clone)]
+pub struct RateLimitExhausted {
+    pub resource: RateLimitResource,
+    /// When the budget is reset.
+    pub reset: DateTime<Utc>,
+}
+
+impl fmt::Display for RateLimitExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the `{}` rate limit budget is exhausted until {}",
+            self.resource, self.reset
+        )
+    }
+}
+
+impl std::error::Error for RateLimitExhausted {}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    limit: u64,
+    remaining: u64,
+    /// Unix timestamp at which the budget is reset.
+    reset: i64,
+}
+
+type BucketKey = (Option<CacheIdentity>, RateLimitResource);
+
+/// Rate limit budgets, shared between clones.
+#[derive(Clone)]
+pub struct RateLimitBudget {
+    buckets: Arc<Mutex<HashMap<BucketKey, Bucket>>>,
+    reserve: f64,
+    max_wait: Option<Duration>,
+}
+
+enum Admission {
+    Proceed,
+    Wait(Duration),
+    Reject(RateLimitExhausted),
+}
+
+impl RateLimitBudget {
+    /// Creates an empty budget, without reserve and waiting for as long as
+    /// needed.
+    pub fn new() -> Self {
+        Self {
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+            reserve: 0.0,
+            max_wait: None,
+        }
+    }
+
+    /// Sets the fraction (between `0.0` and `1.0`) of each budget which
+    /// requests made through this clone must leave untouched.
+    pub fn reserve(mut self, fraction: f64) -> Self {
+        self.reserve = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets how long a request may be held back until its budget is reset.
+    /// Requests which would have to wait longer fail with
+    /// [RateLimitExhausted]; `Duration::ZERO` rejects them right away.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    fn admit(&self, key: &BucketKey) -> Admission {
+        let mut buckets = self.buckets.lock().unwrap();
+        let Some(bucket) = buckets.get_mut(key) else {
+            return Admission::Proceed;
+        };
+
+        let now = Utc::now().timestamp();
+        if now >= bucket.reset {
+            bucket.remaining = bucket.limit;
+        }
+
+        let reserved = (bucket.limit as f64 * self.reserve).ceil() as u64;
+        if bucket.remaining > reserved {
+            bucket.remaining -= 1;
+            return Admission::Proceed;
+        }
+
+        // Wait one more second, as the reset timestamp is truncated.
+        let wait = Duration::from_secs((bucket.reset - now).max(0) as u64 + 1);
+        match self.max_wait {
+            Some(max_wait) if wait > max_wait => Admission::Reject(RateLimitExhausted {
+                resource: key.1.clone(),
+                reset: DateTime::from_timestamp(bucket.reset, 0).unwrap_or_default(),
+            }),
+            _ => Admission::Wait(wait),
+        }
+    }
+
+    async fn acquire(&self, key: &BucketKey) -> Result<(), RateLimitExhausted> {
+        loop {
+            match self.admit(key) {
+                Admission::Proceed => return Ok(()),
+                Admission::Reject(err) => return Err(err),
+                Admission::Wait(wait) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        "`{}` rate limit budget is exhausted, waiting {:?}",
+                        key.1,
+                        wait
+                    );
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+
+    fn record(
+        &self,
+        identity: Option<CacheIdentity>,
+        fallback: RateLimitResource,
+        headers: &HeaderMap,
+    ) {
+        fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            headers.get(name)?.to_str().ok()?.parse().ok()
+        }
+
+        let (Some(limit), Some(remaining), Some(reset)) = (
+            header::<u64>(headers, "x-ratelimit-limit"),
+            header::<u64>(headers, "x-ratelimit-remaining"),
+            header::<i64>(headers, "x-ratelimit-reset"),
+        ) else {
+            return;
+        };
+        let resource = headers
+            .get("x-ratelimit-resource")
+            .and_then(|value| value.to_str().ok())
+            .map(RateLimitResource::from)
+            .unwrap_or(fallback);
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry((identity, resource)).or_insert(Bucket {
+            limit,
+            remaining,
+            reset,
+        });
+        // Responses to concurrent requests may arrive out of order; within a
+        // window, the lowest remaining count is the most recent one.
+        if bucket.reset == reset {
+            bucket.remaining = bucket.remaining.min(remaining);
+        } else if bucket.reset < reset {
+            bucket.remaining = remaining;
+            bucket.reset = reset;
+        }
+        bucket.limit = limit;
+    }
+}
+
+impl Default for RateLimitBudget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RateLimitBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitBudget")
+            .field("reserve", &self.reserve)
+            .field("max_wait", &self.max_wait)
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+/// Layer that holds back requests according to a [RateLimitBudget].
+pub struct RateLimitLayer {
+    budget: Option<RateLimitBudget>,
+}
+
+impl RateLimitLayer {
+    pub fn new(budget: Option<RateLimitBudget>) -> Self {
+        RateLimitLayer { budget }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit {
+            inner,
+            budget: self.budget.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+/// Service that holds back requests according to a [RateLimitBudget].
+pub struct RateLimit<S> {
+    inner: S,
+    budget: Option<RateLimitBudget>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RateLimit<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    ReqBody: Send + 'static,
+{
+    type Error = BoxError;
+    type Response = S::Response;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        // Use the service which was polled ready, leaving a fresh clone behind.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let Some(budget) = self.budget.clone() else {
+            let future = inner.call(req);
+            return Box::pin(async move { future.await.map_err(Into::into) });
+        };
+
+        let identity = CacheIdentity::from_request(&req);
+        let resource = RateLimitResource::of_request(&req);
+
+        Box::pin(async move {
+            let key = (identity, resource);
+            budget.acquire(&key).await?;
+
+            let response = inner.call(req).await.map_err(Into::into)?;
+            let (identity, resource) = key;
+            budget.record(identity, resource, response.headers());
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(remaining: u64, reset: i64, resource: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "10".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", remaining.into());
+        headers.insert("x-ratelimit-reset", reset.into());
+        headers.insert("x-ratelimit-resource", resource.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn classifies_requests() {
+        let resource =
+            |path: &str| RateLimitResource::of_request(&Request::get(path).body(()).unwrap());
+
+        assert_eq!(resource("/repos/owner/repo"), RateLimitResource::Core);
+        assert_eq!(resource("/search/issues?q=a"), RateLimitResource::Search);
+        assert_eq!(resource("/search/code?q=a"), RateLimitResource::CodeSearch);
+        assert_eq!(resource("/graphql"), RateLimitResource::Graphql);
+        assert_eq!(resource("/api/graphql"), RateLimitResource::Graphql);
+        assert_eq!(
+            resource("/api/v3/search/code"),
+            RateLimitResource::CodeSearch
+        );
+    }
+
+    #[test]
+    fn keeps_the_reserve() {
+        let budget = RateLimitBudget::new();
+        let background = budget.clone().reserve(0.5).max_wait(Duration::ZERO);
+        let key = (None, RateLimitResource::Core);
+        let reset = Utc::now().timestamp() + 3600;
+
+        budget.record(None, RateLimitResource::Core, &headers(6, reset, "core"));
+
+        assert!(matches!(background.admit(&key), Admission::Proceed));
+        assert!(matches!(background.admit(&key), Admission::Reject(_)));
+        // Interactive traffic may use the reserve.
+        assert!(matches!(budget.admit(&key), Admission::Proceed));
+    }
+
+    #[test]
+    fn partitions_by_identity_and_resource() {
+        let budget = RateLimitBudget::new().max_wait(Duration::ZERO);
+        let identity = Some(CacheIdentity::new("installation:1"));
+        let reset = Utc::now().timestamp() + 3600;
+
+        budget.record(
+            identity.clone(),
+            RateLimitResource::Core,
+            &headers(0, reset, "search"),
+        );
+
+        assert!(matches!(
+            budget.admit(&(identity.clone(), RateLimitResource::Search)),
+            Admission::Reject(RateLimitExhausted {
+                resource: RateLimitResource::Search,
+                ..
+            })
+        ));
+        assert!(matches!(
+            budget.admit(&(identity, RateLimitResource::Core)),
+            Admission::Proceed
+        ));
+        assert!(matches!(
+            budget.admit(&(None, RateLimitResource::Search)),
+            Admission::Proceed
+        ));
+    }
+
+    #[test]
+    fn replenishes_after_reset() {
+        let budget = RateLimitBudget::new().max_wait(Duration::ZERO);
+        let key = (None, RateLimitResource::Core);
+
+        budget.record(
+            None,
+            RateLimitResource::Core,
+            &headers(0, Utc::now().timestamp() - 1, "core"),
+        );
+
+        assert!(matches!(budget.admit(&key), Admission::Proceed));
+    }
+}
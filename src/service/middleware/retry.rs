@@ -5,8 +5,10 @@ use hyper_util::client::legacy::Error;
 use std::sync::Arc;
 use std::time::Duration;
 use tower::retry::Policy;
+use web_time::Instant;
 
 use crate::body::OctoBody;
+use crate::Retryable;
 
 fn header_as_u64(headers: &HeaderMap<HeaderValue>, header: impl AsHeaderName) -> Option<u64> {
     headers.get(header)?.to_str().ok()?.parse().ok()
@@ -15,6 +17,25 @@ fn header_as_i64(headers: &HeaderMap<HeaderValue>, header: impl AsHeaderName) ->
     headers.get(header)?.to_str().ok()?.parse().ok()
 }
 
+/// Returns whether `req` may be sent again after `result`.
+///
+/// Requests with an idempotent method (`GET`, `HEAD`, `PUT`, `DELETE`, ...) or marked as
+/// [`Retryable`] can always be replayed. Other requests (e.g. `POST`) are only replayed if
+/// GitHub did not process them: the connection could not be established, or the request was
+/// rate limited.
+fn may_replay<B>(req: &Request<OctoBody>, result: &Result<Response<B>, Error>) -> bool {
+    if req.method().is_idempotent() || req.extensions().get::<Retryable>().is_some() {
+        return true;
+    }
+    match result {
+        Ok(response) => matches!(
+            response.status(),
+            http::StatusCode::TOO_MANY_REQUESTS | http::StatusCode::FORBIDDEN
+        ),
+        Err(err) => err.is_connect(),
+    }
+}
+
 /// Gather metrics about retry behavior when handling rate limit headers.
 pub trait RateLimitMetrics: Send + Sync {
     /// An error occurred and either was not a 403/429, or did not have any rate limit headers
@@ -34,6 +55,16 @@ pub trait RateLimitMetrics: Send + Sync {
         retries_remaining: usize,
         waiting_seconds: u64,
     );
+    /// A 5xx/429 error or a connection error occurred (`status_code` is `None` for the latter),
+    /// and [`RetryConfig::ExponentialBackoff`] will wait for `delay` before retrying.
+    fn backoff(
+        &self,
+        _req: &Request<OctoBody>,
+        _status_code: Option<http::StatusCode>,
+        _retries_remaining: usize,
+        _delay: Duration,
+    ) {
+    }
 }
 
 /// Simple No-op struct for users who do not care about collecting retry metrics
@@ -56,6 +87,10 @@ impl RateLimitMetrics for NoOpRateLimitMetrics {
     }
 }
 
+/// Retry policy of the client.
+///
+/// Whatever the variant, requests which are not idempotent (e.g. `POST`) are not retried after a
+/// server error or a timeout, unless they are marked as [`Retryable`].
 #[derive(Clone)]
 pub enum RetryConfig {
     None,
@@ -74,6 +109,143 @@ pub enum RetryConfig {
         max_retries: usize,
         min_wait_seconds: u64,
     },
+    /// Retry server errors (5xx), 429s, connection errors and timeouts after an exponentially
+    /// growing delay, see [`ExponentialBackoff`].
+    ExponentialBackoff(ExponentialBackoff),
+}
+
+/// Exponential backoff parameters for [`RetryConfig::ExponentialBackoff`].
+///
+/// The `n`-th retry waits for `base_delay * multiplier^n`, capped at `max_delay`. With full
+/// jitter (the default), a random delay between zero and that value is used instead, so that
+/// clients failing together don't retry together.
+///
+/// ```
+/// use octocrab::service::middleware::retry::{ExponentialBackoff, RetryConfig};
+/// use std::time::Duration;
+///
+/// let retry_config = RetryConfig::ExponentialBackoff(
+///     ExponentialBackoff::new()
+///         .max_retries(5)
+///         .base_delay(Duration::from_millis(200))
+///         .deadline(Duration::from_secs(30)),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ExponentialBackoff {
+    metrics: Arc<dyn RateLimitMetrics>,
+    max_retries: usize,
+    base_delay: Duration,
+    multiplier: f64,
+    max_delay: Duration,
+    jitter: bool,
+    deadline: Option<Duration>,
+    // Per request state, the policy is cloned for each request.
+    attempt: u32,
+    first_failure: Option<Instant>,
+}
+
+impl ExponentialBackoff {
+    /// Creates a policy retrying 3 times, starting with a 500ms delay doubled on each retry up to
+    /// 30s, with full jitter and no deadline.
+    pub fn new() -> Self {
+        Self {
+            metrics: Arc::new(NoOpRateLimitMetrics),
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            deadline: None,
+            attempt: 0,
+            first_failure: None,
+        }
+    }
+
+    pub fn metrics(mut self, metrics: Arc<dyn RateLimitMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the factor applied to the delay after each retry.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Sets the maximum delay between two retries.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enables or disables full jitter.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Stops retrying once `deadline` has elapsed since the first failure.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Returns the delay before the next retry, or `None` if the request should not be retried.
+    fn next_delay(&mut self) -> Option<Duration> {
+        if self.attempt as usize >= self.max_retries {
+            return None;
+        }
+
+        let exponential = self.base_delay.as_secs_f64() * self.multiplier.powi(self.attempt as i32);
+        let mut delay = Duration::try_from_secs_f64(exponential)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            delay = delay.mul_f64(random_fraction());
+        }
+
+        let first_failure = *self.first_failure.get_or_insert_with(Instant::now);
+        if let Some(deadline) = self.deadline {
+            if first_failure.elapsed() + delay > deadline {
+                return None;
+            }
+        }
+
+        self.attempt += 1;
+        Some(delay)
+    }
+
+    fn retries_remaining(&self) -> usize {
+        self.max_retries.saturating_sub(self.attempt as usize)
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns a random number in `[0, 1)`.
+fn random_fraction() -> f64 {
+    let mut bytes = [0; 8];
+    if getrandom::getrandom(&mut bytes).is_err() {
+        return 1.0;
+    }
+    // Keep 53 bits, the precision of an f64.
+    (u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64
 }
 
 impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
@@ -84,6 +256,10 @@ impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
         req: &mut Request<OctoBody>,
         result: &mut Result<Response<B>, Error>,
     ) -> Option<Self::Future> {
+        if !may_replay(req, result) {
+            return None;
+        }
+
         match self {
             RetryConfig::None => None,
             RetryConfig::Simple(count) => match result {
@@ -161,6 +337,31 @@ impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
                     None
                 }
             }
+            RetryConfig::ExponentialBackoff(backoff) => {
+                let (status, retry_after) = match result {
+                    Ok(response)
+                        if response.status().is_server_error()
+                            || response.status() == http::StatusCode::TOO_MANY_REQUESTS =>
+                    {
+                        (
+                            Some(response.status()),
+                            header_as_u64(response.headers(), "retry-after"),
+                        )
+                    }
+                    Ok(_) => return None,
+                    Err(_) => (None, None),
+                };
+
+                let mut delay = backoff.next_delay()?;
+                if let Some(secs) = retry_after {
+                    delay = delay.max(Duration::from_secs(secs));
+                }
+
+                backoff
+                    .metrics
+                    .backoff(req, status, backoff.retries_remaining(), delay);
+                Some(tokio::time::sleep(delay).boxed())
+            }
         }
     }
 
@@ -180,6 +381,9 @@ impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
                 for (name, value) in req.headers() {
                     new_req = new_req.header(name, value);
                 }
+                if let Some(retryable) = req.extensions().get::<Retryable>() {
+                    new_req = new_req.extension(*retryable);
+                }
 
                 let new_req = new_req.body(body).expect(
                     "This should never panic, as we are cloning a components from existing request",
@@ -189,3 +393,47 @@ impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_up_to_max_delay() {
+        let mut backoff = ExponentialBackoff::new()
+            .max_retries(5)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5))
+            .jitter(false);
+
+        let delays: Vec<_> = std::iter::from_fn(|| backoff.next_delay())
+            .map(|delay| delay.as_secs())
+            .collect();
+        assert_eq!(delays, vec![1, 2, 4, 5, 5]);
+    }
+
+    #[test]
+    fn backoff_jitter_stays_below_delay() {
+        let mut backoff = ExponentialBackoff::new()
+            .max_retries(100)
+            .base_delay(Duration::from_secs(1))
+            .multiplier(1.0);
+
+        while let Some(delay) = backoff.next_delay() {
+            assert!(delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn backoff_respects_deadline() {
+        let mut backoff = ExponentialBackoff::new()
+            .max_retries(10)
+            .base_delay(Duration::from_secs(1))
+            .jitter(false)
+            .deadline(Duration::from_secs(3));
+
+        assert_eq!(backoff.next_delay(), Some(Duration::from_secs(1)));
+        assert_eq!(backoff.next_delay(), Some(Duration::from_secs(2)));
+        assert_eq!(backoff.next_delay(), None);
+    }
+}
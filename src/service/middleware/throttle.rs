@@ -0,0 +1,220 @@
+//! Throttling to stay clear of GitHub's secondary rate limits.
+//!
+//! Following the [documented guidance], [ThrottleLayer]:
+//!
+//! - caps the number of in-flight requests,
+//! - spaces out mutating requests (`POST`, `PATCH`, `PUT` and `DELETE`), by
+//!   one second by default,
+//! - holds back all requests for the duration given by the `retry-after`
+//!   header of a 403/429 response.
+//!
+//! [documented guidance]: https://docs.github.com/en/rest/using-the-rest-api/best-practices-for-using-the-rest-api?apiVersion=2022-11-28#avoid-concurrent-requests
+//!
+//! ```no_run
+//! # fn main() -> octocrab::Result<()> {
+//! use octocrab::service::middleware::throttle::ThrottleConfig;
+//! use std::time::Duration;
+//!
+//! let octocrab = octocrab::Octocrab::builder()
+//!     .throttle(
+//!         ThrottleConfig::new()
+//!             .max_concurrent_requests(10)
+//!             .mutation_interval(Duration::from_secs(2)),
+//!     )
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_util::future::BoxFuture;
+use http::{Method, Request, Response, StatusCode};
+use tokio::{sync::Semaphore, time::Instant};
+use tower::{Layer, Service};
+
+/// Maximum number of concurrent requests GitHub allows.
+const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 100;
+const DEFAULT_MUTATION_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Configuration of [ThrottleLayer].
+#[derive(Debug, Clone)]
+pub struct ThrottleConfig {
+    max_concurrent_requests: usize,
+    mutation_interval: Duration,
+}
+
+impl ThrottleConfig {
+    /// Creates the default configuration: at most 100 concurrent requests,
+    /// and one second between mutating requests.
+    pub fn new() -> Self {
+        Self {
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            mutation_interval: DEFAULT_MUTATION_INTERVAL,
+        }
+    }
+
+    /// Sets the maximum number of in-flight requests.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    }
+
+    /// Sets the minimum delay between the start of two mutating requests.
+    pub fn mutation_interval(mut self, mutation_interval: Duration) -> Self {
+        self.mutation_interval = mutation_interval;
+        self
+    }
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ThrottleState {
+    permits: Semaphore,
+    mutation_interval: Duration,
+    /// Earliest time at which the next mutating request may start.
+    next_mutation: Mutex<Instant>,
+    /// Set from the `retry-after` header of a secondary rate limit response.
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl ThrottleState {
+    fn new(config: &ThrottleConfig) -> Self {
+        Self {
+            permits: Semaphore::new(config.max_concurrent_requests),
+            mutation_interval: config.mutation_interval,
+            next_mutation: Mutex::new(Instant::now()),
+            paused_until: Mutex::new(None),
+        }
+    }
+
+    /// Reserves a time slot for a mutating request.
+    fn reserve_mutation(&self) -> Instant {
+        let mut next_mutation = self.next_mutation.lock().unwrap();
+        let slot = std::cmp::max(*next_mutation, Instant::now());
+        *next_mutation = slot + self.mutation_interval;
+        slot
+    }
+
+    fn paused_until(&self) -> Option<Instant> {
+        let paused_until = *self.paused_until.lock().unwrap();
+        paused_until.filter(|until| *until > Instant::now())
+    }
+
+    fn record<B>(&self, response: &Response<B>) {
+        if !matches!(
+            response.status(),
+            StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+        ) {
+            return;
+        }
+        let Some(retry_after) = response
+            .headers()
+            .get(http::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+        else {
+            return;
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            "secondary rate limit hit, pausing requests for {} seconds",
+            retry_after
+        );
+
+        let until = Instant::now() + Duration::from_secs(retry_after);
+        let mut paused_until = self.paused_until.lock().unwrap();
+        if paused_until.is_none_or(|paused_until| paused_until < until) {
+            *paused_until = Some(until);
+        }
+    }
+}
+
+#[derive(Clone)]
+/// Layer that throttles requests according to a [ThrottleConfig].
+pub struct ThrottleLayer {
+    state: Option<Arc<ThrottleState>>,
+}
+
+impl ThrottleLayer {
+    pub fn new(config: Option<&ThrottleConfig>) -> Self {
+        ThrottleLayer {
+            state: config.map(|config| Arc::new(ThrottleState::new(config))),
+        }
+    }
+}
+
+impl<S> Layer<S> for ThrottleLayer {
+    type Service = Throttle<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Throttle {
+            inner,
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+/// Service that throttles requests according to a [ThrottleConfig].
+pub struct Throttle<S> {
+    inner: S,
+    state: Option<Arc<ThrottleState>>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for Throttle<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Error = S::Error;
+    type Response = S::Response;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        // Use the service which was polled ready, leaving a fresh clone behind.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let Some(state) = self.state.clone() else {
+            return Box::pin(inner.call(req));
+        };
+
+        let is_mutation = matches!(
+            *req.method(),
+            Method::POST | Method::PATCH | Method::PUT | Method::DELETE
+        );
+
+        Box::pin(async move {
+            if let Some(until) = state.paused_until() {
+                tokio::time::sleep_until(until).await;
+            }
+            if is_mutation {
+                tokio::time::sleep_until(state.reserve_mutation()).await;
+            }
+
+            let _permit = state
+                .permits
+                .acquire()
+                .await
+                .expect("the semaphore is never closed");
+            let response = inner.call(req).await?;
+            state.record(&response);
+            Ok(response)
+        })
+    }
+}
@@ -1 +1,2 @@
 pub mod middleware;
+pub mod webhook;
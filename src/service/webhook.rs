@@ -0,0 +1,340 @@
+//! A [`Service`] receiving GitHub webhook deliveries.
+//!
+//! [`WebhookReceiver`] can be mounted in any `tower`-compatible HTTP server (e.g. `axum` or
+//! `hyper`). For each delivery it:
+//!
+//! 1. verifies the `X-Hub-Signature-256` signature with a [`WebhookVerifier`],
+//! 2. decodes the payload according to the `X-GitHub-Event` header,
+//! 3. skips deliveries whose `X-GitHub-Delivery` id was already received (GitHub may redeliver
+//!    the same event),
+//! 4. dispatches the event to the handler registered for its kind.
+//!
+//! ```no_run
+//! use octocrab::models::webhook_events::{payload::IssuesWebhookEventPayload, WebhookVerifier};
+//! use octocrab::service::webhook::WebhookReceiver;
+//!
+//! let receiver = WebhookReceiver::new(WebhookVerifier::new("my webhook secret"))
+//!     .on(|delivery: octocrab::service::webhook::WebhookDelivery<IssuesWebhookEventPayload>| async move {
+//!         println!("issue #{} was {:?}", delivery.payload.issue.number, delivery.payload.action);
+//!         Ok(())
+//!     });
+//! // `receiver` implements `tower::Service<http::Request<B>>`, pass it to your HTTP server.
+//! ```
+//!
+//! Responses follow these rules, so that GitHub records the outcome of each delivery correctly:
+//!
+//! | Situation                                         | Status                       |
+//! |---------------------------------------------------|------------------------------|
+//! | Handler succeeded, or delivery already received   | `200 OK`                     |
+//! | No handler registered for this kind of event      | `204 No Content`             |
+//! | Request is not a `POST`                           | `405 Method Not Allowed`     |
+//! | Missing or invalid signature                      | `401 Unauthorized`           |
+//! | Missing `X-GitHub-Event` header, malformed body   | `400 Bad Request`            |
+//! | Body larger than the configured limit             | `413 Payload Too Large`      |
+//! | Handler failed                                    | `500 Internal Server Error`  |
+
+use std::collections::{HashSet, VecDeque};
+use std::convert::Infallible;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::{Method, Request, Response, StatusCode};
+use http_body_util::{BodyExt, Full, Limited};
+use tower::{BoxError, Service};
+
+use crate::models::orgs::Organization;
+use crate::models::webhook_events::payload::WebhookPayload;
+use crate::models::webhook_events::signature::{DELIVERY_HEADER, EVENT_HEADER};
+use crate::models::webhook_events::{
+    EventInstallation, WebhookError, WebhookEvent, WebhookEventPayload, WebhookEventType,
+    WebhookVerifier,
+};
+use crate::models::{Author, Repository};
+use crate::Error;
+
+/// Maximum payload size GitHub sends, see "Webhook payload size cap".
+const DEFAULT_MAX_BODY_SIZE: usize = 25 * 1024 * 1024;
+const DEFAULT_DEDUPLICATION_CAPACITY: usize = 1024;
+
+/// A webhook delivery, as passed to handlers.
+///
+/// `P` is either a specific payload type (e.g.
+/// [`IssuesWebhookEventPayload`](crate::models::webhook_events::payload::IssuesWebhookEventPayload))
+/// or the whole [`WebhookEventPayload`] for fallback handlers.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct WebhookDelivery<P> {
+    /// The unique identifier of the delivery (`X-GitHub-Delivery` header), if present.
+    pub id: Option<String>,
+    pub kind: WebhookEventType,
+    pub sender: Option<Author>,
+    pub repository: Option<Repository>,
+    pub organization: Option<Organization>,
+    pub installation: Option<EventInstallation>,
+    pub payload: P,
+}
+
+type Handler = Arc<
+    dyn Fn(WebhookDelivery<WebhookEventPayload>) -> BoxFuture<'static, Result<(), BoxError>>
+        + Send
+        + Sync,
+>;
+
+/// Service that verifies, decodes and dispatches GitHub webhook deliveries.
+#[derive(Clone)]
+pub struct WebhookReceiver {
+    verifier: Arc<WebhookVerifier>,
+    handlers: Arc<Vec<(WebhookEventType, Handler)>>,
+    fallback: Option<Handler>,
+    deliveries: Arc<Mutex<RecentDeliveries>>,
+    max_body_size: usize,
+}
+
+impl WebhookReceiver {
+    /// Creates a receiver accepting deliveries signed according to `verifier`.
+    pub fn new(verifier: WebhookVerifier) -> Self {
+        Self {
+            verifier: Arc::new(verifier),
+            handlers: Arc::new(Vec::new()),
+            fallback: None,
+            deliveries: Arc::new(Mutex::new(RecentDeliveries::new(
+                DEFAULT_DEDUPLICATION_CAPACITY,
+            ))),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+
+    /// Registers `handler` for the events carrying payloads of type `P`.
+    ///
+    /// Registering a second handler for the same kind of event replaces the first one.
+    pub fn on<P, F, Fut>(mut self, handler: F) -> Self
+    where
+        P: WebhookPayload,
+        F: Fn(WebhookDelivery<P>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), BoxError>> + Send + 'static,
+    {
+        let handler: Handler = Arc::new(move |delivery: WebhookDelivery<WebhookEventPayload>| {
+            let WebhookDelivery {
+                id,
+                kind,
+                sender,
+                repository,
+                organization,
+                installation,
+                payload,
+            } = delivery;
+            match P::from_payload(payload) {
+                Ok(payload) => Box::pin(handler(WebhookDelivery {
+                    id,
+                    kind,
+                    sender,
+                    repository,
+                    organization,
+                    installation,
+                    payload,
+                })),
+                Err(_) => Box::pin(futures::future::ready(Ok(()))),
+            }
+        });
+
+        let kind = P::kind();
+        let handlers = Arc::make_mut(&mut self.handlers);
+        handlers.retain(|(registered, _)| *registered != kind);
+        handlers.push((kind, handler));
+        self
+    }
+
+    /// Registers `handler` for all the events without a specific handler.
+    pub fn on_any<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(WebhookDelivery<WebhookEventPayload>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), BoxError>> + Send + 'static,
+    {
+        self.fallback = Some(Arc::new(move |delivery| Box::pin(handler(delivery))));
+        self
+    }
+
+    /// Sets how many delivery ids are remembered to detect redeliveries. Defaults to 1024, `0`
+    /// disables deduplication.
+    pub fn deduplication_capacity(mut self, capacity: usize) -> Self {
+        self.deliveries = Arc::new(Mutex::new(RecentDeliveries::new(capacity)));
+        self
+    }
+
+    /// Sets the maximum accepted body size in bytes. Defaults to 25 MiB, the largest payload
+    /// GitHub sends.
+    pub fn max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    fn handler_for(&self, kind: &WebhookEventType) -> Option<Handler> {
+        self.handlers
+            .iter()
+            .find(|(registered, _)| registered == kind)
+            .map(|(_, handler)| handler.clone())
+            .or_else(|| self.fallback.clone())
+    }
+
+    async fn receive<B>(self, request: Request<B>) -> Response<Full<Bytes>>
+    where
+        B: http_body::Body<Data = Bytes>,
+        B::Error: Into<BoxError>,
+    {
+        if request.method() != Method::POST {
+            return respond(StatusCode::METHOD_NOT_ALLOWED, "only POST is supported");
+        }
+
+        let (parts, body) = request.into_parts();
+        let body = match Limited::new(body, self.max_body_size).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(err) if err.is::<http_body_util::LengthLimitError>() => {
+                return respond(StatusCode::PAYLOAD_TOO_LARGE, "payload too large");
+            }
+            Err(_) => return respond(StatusCode::BAD_REQUEST, "failed to read body"),
+        };
+
+        let event =
+            match WebhookEvent::try_from_verified_request(&self.verifier, &parts.headers, &body) {
+                Ok(event) => event,
+                Err(Error::Webhook { source, .. }) => {
+                    let status = match source {
+                        WebhookError::MissingHeader(EVENT_HEADER) => StatusCode::BAD_REQUEST,
+                        _ => StatusCode::UNAUTHORIZED,
+                    };
+                    return respond(status, &source.to_string());
+                }
+                Err(err) => return respond(StatusCode::BAD_REQUEST, &err.to_string()),
+            };
+
+        let id = parts
+            .headers
+            .get(DELIVERY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let Some(handler) = self.handler_for(&event.kind) else {
+            return respond(StatusCode::NO_CONTENT, "");
+        };
+
+        if let Some(ref id) = id {
+            if !self.deliveries.lock().unwrap().insert(id) {
+                return respond(StatusCode::OK, "delivery already received");
+            }
+        }
+
+        let delivery = WebhookDelivery {
+            id,
+            kind: event.kind,
+            sender: event.sender,
+            repository: event.repository,
+            organization: event.organization,
+            installation: event.installation,
+            payload: event.specific,
+        };
+        let id = delivery.id.clone();
+
+        match handler(delivery).await {
+            Ok(()) => respond(StatusCode::OK, ""),
+            Err(err) => {
+                // Let GitHub (or an operator) redeliver the event.
+                if let Some(ref id) = id {
+                    self.deliveries.lock().unwrap().remove(id);
+                }
+
+                #[cfg(feature = "tracing")]
+                tracing::error!("webhook handler failed: {}", err);
+                #[cfg(not(feature = "tracing"))]
+                let _ = err;
+
+                respond(StatusCode::INTERNAL_SERVER_ERROR, "handler failed")
+            }
+        }
+    }
+}
+
+impl fmt::Debug for WebhookReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookReceiver")
+            .field(
+                "handlers",
+                &self
+                    .handlers
+                    .iter()
+                    .map(|(kind, _)| kind)
+                    .collect::<Vec<_>>(),
+            )
+            .field("fallback", &self.fallback.is_some())
+            .field("max_body_size", &self.max_body_size)
+            .finish()
+    }
+}
+
+impl<B> Service<Request<B>> for WebhookReceiver
+where
+    B: http_body::Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let receiver = self.clone();
+        Box::pin(async move { Ok(receiver.receive(request).await) })
+    }
+}
+
+fn respond(status: StatusCode, message: &str) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::copy_from_slice(message.as_bytes())));
+    *response.status_mut() = status;
+    response
+}
+
+/// Bounded set of the most recently received delivery ids.
+struct RecentDeliveries {
+    capacity: usize,
+    order: VecDeque<String>,
+    ids: HashSet<String>,
+}
+
+impl RecentDeliveries {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::new(),
+            ids: HashSet::new(),
+        }
+    }
+
+    /// Returns `false` if `id` was already received.
+    fn insert(&mut self, id: &str) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+        if !self.ids.insert(id.to_owned()) {
+            return false;
+        }
+        self.order.push_back(id.to_owned());
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        true
+    }
+
+    fn remove(&mut self, id: &str) {
+        if self.ids.remove(id) {
+            self.order.retain(|received| received != id);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use octocrab::OctocrabBuilder;
+use secrecy::ExposeSecret;
+use wiremock::{
+    matchers::{body_json, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+// A single test, as it sets the environment of the whole process.
+#[tokio::test]
+async fn should_configure_client_from_actions_environment() {
+    let ghes = MockServer::start().await;
+    let token_service = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/user"))
+        .and(header("authorization", "Bearer ghs_actions"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(1)
+        .mount(&ghes)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(header("authorization", "Bearer ghs_actions"))
+        .and(body_json(
+            serde_json::json!({ "query": "{ viewer { login } }" }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "data": { "viewer": { "login": "bot" } } })),
+        )
+        .expect(1)
+        .mount(&ghes)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/oidc/token"))
+        .and(query_param("api-version", "2.0"))
+        .and(query_param("audience", "sts.amazonaws.com"))
+        .and(header("authorization", "Bearer oidc_request_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "count": 1,
+            "value": "header.claims.signature",
+        })))
+        .expect(1)
+        .mount(&token_service)
+        .await;
+
+    std::env::remove_var("GITHUB_TOKEN");
+    let client = OctocrabBuilder::from_actions_env();
+    assert!(
+        client.is_err(),
+        "expected an error without GITHUB_TOKEN in the environment"
+    );
+
+    std::env::set_var("GITHUB_TOKEN", "ghs_actions");
+    std::env::set_var("GITHUB_SERVER_URL", ghes.uri());
+    std::env::set_var("GITHUB_API_URL", format!("{}/api/v3", ghes.uri()));
+    std::env::set_var("GITHUB_GRAPHQL_URL", format!("{}/api/graphql", ghes.uri()));
+    std::env::set_var(
+        "ACTIONS_ID_TOKEN_REQUEST_URL",
+        format!("{}/oidc/token?api-version=2.0", token_service.uri()),
+    );
+    std::env::set_var("ACTIONS_ID_TOKEN_REQUEST_TOKEN", "oidc_request_token");
+
+    let client = OctocrabBuilder::from_actions_env()
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let status = client._get("/user").await.unwrap().status();
+    assert_eq!(status, 200);
+
+    let viewer: serde_json::Value = client
+        .graphql(&serde_json::json!({ "query": "{ viewer { login } }" }))
+        .await
+        .unwrap();
+    assert_eq!(viewer["viewer"]["login"], "bot");
+
+    let id_token = client
+        .actions_id_token(Some("sts.amazonaws.com"))
+        .await
+        .unwrap();
+    assert_eq!(id_token.expose_secret(), "header.claims.signature");
+}
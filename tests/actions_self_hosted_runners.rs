@@ -10,7 +10,7 @@ use octocrab::{
         actions::{SelfHostedRunner, SelfHostedRunnerJitConfig, SelfHostedRunnerToken},
         RunnerGroupId,
     },
-    Octocrab, Page,
+    Octocrab, Page, Paginate,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -182,6 +182,32 @@ async fn should_return_page_with_org_self_hosted_runners() {
     assert_eq!(items, expected_runners.runners);
 }
 
+#[tokio::test]
+async fn should_return_all_org_self_hosted_runners() {
+    let expected_runners: FakePage =
+        serde_json::from_str(include_str!("resources/self_hosted_runners.json")).unwrap();
+    let test_context = test_context(
+        Scope::Org,
+        "GET",
+        "/actions/runners",
+        StatusCode::OK,
+        Some(expected_runners.clone()),
+    )
+    .await;
+    let result = test_context
+        .client
+        .actions()
+        .list_org_self_hosted_runners(ORG)
+        .send_all()
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap(), expected_runners.runners);
+}
+
 #[tokio::test]
 async fn should_return_page_with_repo_self_hosted_runners() {
     let expected_runners: FakePage =
@@ -0,0 +1,94 @@
+// Tests for calls to the /app-manifests/{code}/conversions API.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::{apps::AppManifest, AppId};
+use octocrab::Octocrab;
+use secrecy::ExposeSecret;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const CODE: &str = "a1b2c3";
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/app-manifests/{CODE}/conversions")))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /app-manifests/{CODE}/conversions was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_created_app_with_credentials() {
+    let conversion: serde_json::Value =
+        serde_json::from_str(include_str!("resources/app_manifest_conversion.json")).unwrap();
+    let template = ResponseTemplate::new(201).set_body_json(&conversion);
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.apps().create_from_manifest(CODE).await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    let created = result.unwrap();
+    assert_eq!(created.app.id, AppId(1));
+    assert_eq!(created.app.slug.as_deref(), Some("octoapp"));
+    assert_eq!(
+        created.app.client_id.as_deref(),
+        Some("Iv1.8a61f9b3a7aba766")
+    );
+    assert_eq!(created.app.client_secret, None);
+    assert_eq!(
+        created.client_secret().expose_secret(),
+        "1726be1638095a19edd134c77bde3aa2ece1e5d8"
+    );
+    assert_eq!(
+        created.webhook_secret().map(ExposeSecret::expose_secret),
+        Some("e340154128314309424b7c8e90325147d99fdafa")
+    );
+    assert!(!format!("{created:?}").contains("1726be1638095a19edd134c77bde3aa2ece1e5d8"));
+    assert!(created.encoding_key().is_ok());
+}
+
+#[test]
+fn should_serialize_manifest() {
+    let manifest = AppManifest::new("https://example.com")
+        .name("Octocat App")
+        .hook_url("https://example.com/webhooks")
+        .redirect_url("https://example.com/redirect")
+        .default_event("issues")
+        .default_permission("issues", "write")
+        .default_permission("contents", "read");
+
+    assert_eq!(
+        serde_json::to_value(&manifest).unwrap(),
+        serde_json::json!({
+            "name": "Octocat App",
+            "url": "https://example.com",
+            "hook_attributes": { "url": "https://example.com/webhooks", "active": true },
+            "redirect_url": "https://example.com/redirect",
+            "public": false,
+            "default_events": ["issues"],
+            "default_permissions": { "contents": "read", "issues": "write" },
+            "request_oauth_on_install": false,
+            "setup_on_update": false,
+        })
+    );
+}
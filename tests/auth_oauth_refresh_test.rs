@@ -0,0 +1,154 @@
+use std::sync::{Arc, Mutex};
+
+use octocrab::{
+    auth::{OAuth, OAuthRefresh},
+    Octocrab,
+};
+use secrecy::ExposeSecret;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/login/oauth/access_token"))
+        .and(header("accept", "application/json"))
+        .and(body_partial_json(json!({
+            "client_id": "client_id",
+            "client_secret": "client_secret",
+            "grant_type": "refresh_token",
+            "refresh_token": "ghr_first",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "ghu_second",
+            "token_type": "bearer",
+            "scope": "",
+            "expires_in": 28800,
+            "refresh_token": "ghr_second",
+            "refresh_token_expires_in": 15897600,
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .and(header("authorization", "bearer ghu_second"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn oauth(expires_in: usize) -> OAuth {
+    serde_json::from_value(json!({
+        "access_token": "ghu_first",
+        "token_type": "bearer",
+        "scope": "",
+        "expires_in": expires_in,
+        "refresh_token": "ghr_first",
+    }))
+    .unwrap()
+}
+
+fn setup_octocrab(mock_server: &MockServer, oauth: OAuth) -> (Octocrab, Arc<Mutex<Vec<String>>>) {
+    let refreshed = Arc::new(Mutex::new(Vec::new()));
+    let on_refresh = refreshed.clone();
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .oauth_refresh(
+            OAuthRefresh::new(oauth, "client_id", "client_secret".to_string())
+                .token_uri(
+                    format!("{}/login/oauth/access_token", mock_server.uri())
+                        .parse()
+                        .unwrap(),
+                )
+                .on_refresh(move |oauth| {
+                    on_refresh.lock().unwrap().push(
+                        oauth
+                            .refresh_token
+                            .as_ref()
+                            .unwrap()
+                            .expose_secret()
+                            .to_owned(),
+                    )
+                }),
+        )
+        .build()
+        .unwrap();
+    (client, refreshed)
+}
+
+#[tokio::test]
+async fn should_refresh_expiring_token() {
+    let mock_server = setup_api().await;
+    let (client, refreshed) = setup_octocrab(&mock_server, oauth(0));
+
+    let response = client._get("/user").await.unwrap();
+    assert_eq!(response.status(), 200);
+    let response = client._get("/user").await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    assert_eq!(*refreshed.lock().unwrap(), vec!["ghr_second".to_owned()]);
+}
+
+#[tokio::test]
+async fn should_refresh_token_after_unauthorized_response() {
+    let mock_server = setup_api().await;
+    let (client, refreshed) = setup_octocrab(&mock_server, oauth(28800));
+
+    let response = client._get("/user").await.unwrap();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(*refreshed.lock().unwrap(), vec!["ghr_second".to_owned()]);
+}
+
+#[tokio::test]
+async fn should_keep_retryable_request_retryable_after_refresh() {
+    let mock_server = setup_api().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/1/comments"))
+        .and(header("authorization", "bearer ghu_second"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/1/comments"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+    let (client, _) = setup_octocrab(&mock_server, oauth(28800));
+
+    let result = client
+        .issues("owner", "repo")
+        .create_comment_retryable(1, "Beep Boop")
+        .await;
+
+    assert!(result.is_err(), "expected error, got {:#?}", result);
+    let replayed = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|request| {
+            request
+                .headers
+                .get("authorization")
+                .map(|value| value.as_bytes())
+                == Some(b"bearer ghu_second")
+        })
+        .count();
+    assert_eq!(replayed, 4);
+}
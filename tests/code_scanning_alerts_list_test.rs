@@ -5,7 +5,7 @@ use wiremock::{
 
 use mock_error::setup_error_handler;
 use octocrab::models::code_scannings::CodeScanningAlert;
-use octocrab::Octocrab;
+use octocrab::{Octocrab, Paginate};
 
 mod mock_error;
 
@@ -86,3 +86,25 @@ async fn check_list_organisation_200() {
         result
     );
 }
+
+#[tokio::test]
+async fn check_list_all_200() {
+    let s = include_str!("resources/codescanning_alerts_multiple.json");
+    let alert: Vec<CodeScanningAlert> = serde_json::from_str(s).unwrap();
+    let template = ResponseTemplate::new(200).set_body_json(&alert);
+    let mock_server = setup_codescanning_list_api(template, false).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .code_scannings(OWNER.to_owned(), REPO.to_owned())
+        .list()
+        .send_all()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:?}",
+        result
+    );
+    assert_eq!(result.unwrap().len(), alert.len());
+}
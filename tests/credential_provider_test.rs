@@ -0,0 +1,72 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use http::HeaderValue;
+use octocrab::{auth::CredentialProvider, Octocrab};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Rotates over a pool of tokens, skipping the rejected ones.
+struct TokenPool {
+    tokens: Vec<&'static str>,
+    next: AtomicUsize,
+    rejected: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl CredentialProvider for TokenPool {
+    async fn authorization(&self, _: &Octocrab) -> octocrab::Result<Option<HeaderValue>> {
+        let next = self.next.fetch_add(1, Ordering::SeqCst) % self.tokens.len();
+        Ok(Some(
+            HeaderValue::from_str(&format!("Bearer {}", self.tokens[next])).unwrap(),
+        ))
+    }
+
+    fn unauthorized(&self) {
+        self.rejected.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn should_use_credentials_from_provider() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .and(header("authorization", "Bearer first"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .and(header("authorization", "Bearer revoked"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let rejected = Arc::new(AtomicUsize::new(0));
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .credential_provider(TokenPool {
+            tokens: vec!["first", "revoked"],
+            next: AtomicUsize::new(0),
+            rejected: rejected.clone(),
+        })
+        .build()
+        .unwrap();
+
+    let statuses = [
+        client._get("/user").await.unwrap().status(),
+        client._get("/user").await.unwrap().status(),
+        client._get("/user").await.unwrap().status(),
+    ];
+
+    assert_eq!(statuses, [200, 401, 200]);
+    assert_eq!(rejected.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,116 @@
+use http_body_util::BodyExt;
+use octocrab::{
+    service::middleware::cache::{fs::FileSystemCache, mem::InMemoryCache, CacheStorage},
+    Octocrab,
+};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ETAG: &str = "\"abcd\"";
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .and(header("if-none-match", ETAG))
+        .respond_with(ResponseTemplate::new(304).append_header("etag", ETAG))
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .append_header("etag", ETAG)
+                .set_body_string("{\"name\":\"repo\"}"),
+        )
+        .with_priority(2)
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn client(mock_server: &MockServer, token: &str, cache: impl CacheStorage + 'static) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token(token.to_string())
+        .cache(cache)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_reuse_cached_body_across_instances() {
+    let mock_server = setup_api().await;
+    let dir = std::env::temp_dir().join(format!("octocrab-http-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let first = client(&mock_server, "token", FileSystemCache::new(&dir).unwrap());
+    let response = first._get("/repos/owner/repo").await.unwrap();
+    response.into_body().collect().await.unwrap();
+
+    // The entry is written in the background.
+    while !std::fs::read_dir(&dir)
+        .unwrap()
+        .any(|entry| entry.unwrap().path().extension() == Some("entry".as_ref()))
+    {
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+
+    // A fresh client with the same credentials revalidates the cached entry.
+    let second = client(&mock_server, "token", FileSystemCache::new(&dir).unwrap());
+    let response = second._get("/repos/owner/repo").await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        second.body_to_string(response).await.unwrap(),
+        "{\"name\":\"repo\"}"
+    );
+
+    // Another identity never sees the cached entry.
+    let other = client(&mock_server, "other", FileSystemCache::new(&dir).unwrap());
+    other._get("/repos/owner/repo").await.unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let conditional: Vec<_> = requests
+        .iter()
+        .map(|request| request.headers.contains_key("if-none-match"))
+        .collect();
+    assert_eq!(conditional, vec![false, true, false]);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn should_partition_cached_bodies_by_identity() {
+    let mock_server = setup_api().await;
+    let cache = InMemoryCache::new();
+
+    let first = client(&mock_server, "token", cache.clone());
+    let response = first._get("/repos/owner/repo").await.unwrap();
+    response.into_body().collect().await.unwrap();
+
+    let other = client(&mock_server, "other", cache.clone());
+    let response = other._get("/repos/owner/repo").await.unwrap();
+    response.into_body().collect().await.unwrap();
+
+    let same = client(&mock_server, "token", cache.clone());
+    let response = same._get("/repos/owner/repo").await.unwrap();
+    assert_eq!(
+        same.body_to_string(response).await.unwrap(),
+        "{\"name\":\"repo\"}"
+    );
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let conditional: Vec<_> = requests
+        .iter()
+        .map(|request| request.headers.contains_key("if-none-match"))
+        .collect();
+    assert_eq!(conditional, vec![false, false, true]);
+    assert_eq!(cache.stats().entries, 2);
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use octocrab::models::{AppId, InstallationId};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_app(uri: &str) -> Octocrab {
+    let key =
+        jsonwebtoken::EncodingKey::from_rsa_pem(include_bytes!("resources/github_app_key.pem"))
+            .unwrap();
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .app(AppId(1), key)
+        .build()
+        .unwrap()
+}
+
+async fn setup_installation_token(mock_server: &MockServer, token: &str) {
+    Mock::given(method("POST"))
+        .and(path("/app/installations/1/access_tokens"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "token": token,
+            "expires_at": "2100-01-01T00:00:00Z",
+            "permissions": {},
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_share_installation_by_org_and_repo() {
+    let mock_server = MockServer::start().await;
+    let installation: serde_json::Value =
+        serde_json::from_str(include_str!("resources/orgs_installation_event.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/orgs/github/installation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&installation))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/github/octocrab/installation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&installation))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_installation_token(&mock_server, "ghs_pooled").await;
+    Mock::given(method("GET"))
+        .and(path("/installation/repositories"))
+        .and(header("authorization", "Bearer ghs_pooled"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let pool = setup_app(&mock_server.uri()).installation_pool().unwrap();
+
+    let by_org = pool.org("GitHub").await.unwrap();
+    let by_repo = pool.repo("github", "octocrab").await.unwrap();
+    let by_org_again = pool.org("github").await.unwrap();
+
+    assert!(Arc::ptr_eq(&by_org, &by_repo));
+    assert!(Arc::ptr_eq(&by_org, &by_org_again));
+    assert_eq!(pool.len(), 1);
+
+    for crab in [by_org, by_repo] {
+        let status = crab
+            ._get("/installation/repositories")
+            .await
+            .unwrap()
+            .status();
+        assert_eq!(status, 200);
+    }
+}
+
+#[tokio::test]
+async fn should_evict_idle_installations() {
+    let mock_server = MockServer::start().await;
+    let pool = setup_app(&mock_server.uri())
+        .installation_pool()
+        .unwrap()
+        .idle_timeout(Duration::ZERO);
+
+    let first = pool.installation(InstallationId(1)).unwrap();
+    pool.evict_idle();
+    assert!(pool.is_empty());
+
+    let second = pool.installation(InstallationId(1)).unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+}
+
+#[tokio::test]
+async fn should_forget_lookups_of_evicted_installations() {
+    let mock_server = MockServer::start().await;
+    let installation: serde_json::Value =
+        serde_json::from_str(include_str!("resources/orgs_installation_event.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/orgs/github/installation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&installation))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/github/octocrab/installation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&installation))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    let pool = setup_app(&mock_server.uri())
+        .installation_pool()
+        .unwrap()
+        .idle_timeout(Duration::from_millis(100));
+
+    pool.org("github").await.unwrap();
+    pool.repo("github", "octocrab").await.unwrap();
+    // The lookups are kept as long as the installation is pooled.
+    pool.org("github").await.unwrap();
+    pool.repo("github", "octocrab").await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    pool.evict_idle();
+    assert!(pool.is_empty());
+
+    // The lookups were evicted with the installation, so they are repeated.
+    pool.org("github").await.unwrap();
+    pool.repo("github", "octocrab").await.unwrap();
+    assert_eq!(pool.len(), 1);
+}
+
+#[tokio::test]
+async fn should_require_app_authentication() {
+    let client = Octocrab::builder().build().unwrap();
+
+    assert!(client.installation_pool().is_err());
+}
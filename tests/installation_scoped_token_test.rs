@@ -0,0 +1,87 @@
+use octocrab::auth::InstallationTokenScope;
+use octocrab::models::{AppId, InstallationId, RepositoryId};
+use octocrab::Octocrab;
+use secrecy::ExposeSecret;
+use wiremock::{
+    matchers::{body_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_app(uri: &str) -> Octocrab {
+    let key =
+        jsonwebtoken::EncodingKey::from_rsa_pem(include_bytes!("resources/github_app_key.pem"))
+            .unwrap();
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .app(AppId(1), key)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_request_token_restricted_to_scope() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/app/installations/1/access_tokens"))
+        .and(body_json(serde_json::json!({
+            "repositories": ["octocrab"],
+            "repository_ids": [42],
+            "permissions": { "contents": "read", "issues": "write" },
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "token": "ghs_scoped",
+            "expires_at": "2100-01-01T00:00:00Z",
+            "permissions": { "contents": "read", "issues": "write" },
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/octocrab/issues"))
+        .and(header("authorization", "Bearer ghs_scoped"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let scope = InstallationTokenScope::new()
+        .repository("octocrab")
+        .repository_id(RepositoryId(42))
+        .permission("issues", "write")
+        .permission("contents", "read");
+    let (crab, token) = setup_app(&mock_server.uri())
+        .scoped_installation_and_token(InstallationId(1), scope)
+        .await
+        .unwrap();
+
+    assert_eq!(token.expose_secret(), "ghs_scoped");
+    let status = crab
+        ._get("/repos/owner/octocrab/issues")
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status, 200);
+}
+
+#[tokio::test]
+async fn should_request_unrestricted_token_without_scope() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/app/installations/1/access_tokens"))
+        .and(body_json(serde_json::json!({})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "token": "ghs_full",
+            "permissions": {},
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (_, token) = setup_app(&mock_server.uri())
+        .scoped_installation_and_token(InstallationId(1), InstallationTokenScope::new())
+        .await
+        .unwrap();
+
+    assert_eq!(token.expose_secret(), "ghs_full");
+}
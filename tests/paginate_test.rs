@@ -0,0 +1,190 @@
+/// Tests sending list builders for the results of every page.
+use octocrab::models::checks::ListCheckRuns;
+use octocrab::{Octocrab, Page, PageCursor, Paginate};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const CHECK_RUNS_PATH: &str = "/repos/owner/repo/commits/main/check-runs";
+
+/// Serves two pages of two check runs each, wrapped in an object with a
+/// `total_count` and linked with a `Link` header.
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+    let mut check_runs: ListCheckRuns =
+        serde_json::from_str(include_str!("resources/commit_check_runs.json")).unwrap();
+    check_runs.total_count = 4;
+
+    // Mounted first, so that it takes precedence over the first page.
+    Mock::given(method("GET"))
+        .and(path(CHECK_RUNS_PATH))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&check_runs))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(CHECK_RUNS_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(&check_runs)
+                .append_header(
+                    "link",
+                    format!(
+                        r#"<{uri}{CHECK_RUNS_PATH}?page=2>; rel="next", <{uri}{CHECK_RUNS_PATH}?page=2>; rel="last""#,
+                        uri = mock_server.uri()
+                    )
+                    .as_str(),
+                ),
+        )
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn received_pages(mock_server: &MockServer) -> usize {
+    mock_server.received_requests().await.unwrap().len()
+}
+
+#[tokio::test]
+async fn should_send_all_wrapped_pages() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let check_runs = client
+        .checks("owner", "repo")
+        .list_check_runs_for_git_ref("main".to_string().into())
+        .send_all()
+        .await
+        .unwrap();
+
+    assert_eq!(check_runs.len(), 4);
+    assert_eq!(
+        check_runs[0].name,
+        "Cargo test on nix (ubuntu-20.04, stable)"
+    );
+    assert_eq!(received_pages(&mock_server).await, 2);
+}
+
+#[tokio::test]
+async fn should_stop_fetching_pages_at_item_cap() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let check_runs = client
+        .checks("owner", "repo")
+        .list_check_runs_for_git_ref("main".to_string().into())
+        .send_at_most(1)
+        .await
+        .unwrap();
+    assert_eq!(check_runs.len(), 1);
+    assert_eq!(received_pages(&mock_server).await, 1);
+
+    let check_runs = client
+        .checks("owner", "repo")
+        .list_check_runs_for_git_ref("main".to_string().into())
+        .send_at_most(3)
+        .await
+        .unwrap();
+    assert_eq!(check_runs.len(), 3);
+    assert_eq!(received_pages(&mock_server).await, 3);
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn should_stream_wrapped_pages() {
+    use futures_util::TryStreamExt;
+
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let stream = client
+        .checks("owner", "repo")
+        .list_check_runs_for_git_ref("main".to_string().into())
+        .stream()
+        .await
+        .unwrap();
+    // The stream doesn't borrow the client.
+    drop(client);
+
+    let check_runs: Vec<_> = stream.try_collect().await.unwrap();
+    assert_eq!(check_runs.len(), 4);
+}
+
+#[tokio::test]
+async fn should_fetch_remaining_pages_concurrently_in_order() {
+    let mock_server = MockServer::start().await;
+    for page in 2..=4 {
+        // Earlier pages respond later, to check that the order is kept.
+        Mock::given(method("GET"))
+            .and(path("/orgs/org/repos"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([{ "id": page }]))
+                    .set_delay(std::time::Duration::from_millis(100 * (5 - page))),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/repos"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{ "id": 1 }]))
+                .append_header(
+                    "link",
+                    format!(
+                        r#"<{uri}/orgs/org/repos?page=2>; rel="next", <{uri}/orgs/org/repos?page=4>; rel="last""#,
+                        uri = mock_server.uri()
+                    )
+                    .as_str(),
+                ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first: Page<serde_json::Value> = client.get("/orgs/org/repos", None::<&()>).await.unwrap();
+    let repos = client.all_pages_concurrent(first, 3).await.unwrap();
+
+    let ids: Vec<_> = repos
+        .iter()
+        .map(|repo| repo["id"].as_u64().unwrap())
+        .collect();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn should_resume_listing_from_persisted_cursor() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first = client
+        .checks("owner", "repo")
+        .list_check_runs_for_git_ref("main".to_string().into())
+        .first_page()
+        .await
+        .unwrap();
+    let saved = serde_json::to_string(&first.cursor().unwrap()).unwrap();
+    drop(client);
+
+    let cursor: PageCursor = serde_json::from_str(&saved).unwrap();
+    assert_eq!(cursor.next().query(), Some("page=2"));
+    let client = setup_octocrab(&mock_server.uri());
+    let second: Page<octocrab::models::checks::CheckRun> = client.resume(&cursor).await.unwrap();
+
+    assert_eq!(second.items.len(), 2);
+    assert_eq!(second.cursor(), None);
+}
+
+#[test]
+fn should_not_deserialize_invalid_cursor() {
+    assert!(serde_json::from_str::<PageCursor>(r#"{ "next": "not a link" }"#).is_err());
+}
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use octocrab::{
+    service::middleware::rate_limit::{RateLimitBudget, RateLimitExhausted, RateLimitResource},
+    Error, Octocrab,
+};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api(remaining: u64) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let reset = chrono::Utc::now().timestamp() + 3600;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .append_header("x-ratelimit-limit", "10")
+                .append_header("x-ratelimit-remaining", remaining.to_string())
+                .append_header("x-ratelimit-reset", reset.to_string())
+                .append_header("x-ratelimit-resource", "core")
+                .set_body_string("{}"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+fn client(mock_server: &MockServer, budget: RateLimitBudget) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("token".to_string())
+        .rate_limit_budget(budget)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_reject_requests_exceeding_the_budget() {
+    let mock_server = setup_api(0).await;
+    let client = client(
+        &mock_server,
+        RateLimitBudget::new().max_wait(Duration::ZERO),
+    );
+
+    client._get("/repos/owner/repo").await.unwrap();
+    let err = client._get("/repos/owner/repo").await.unwrap_err();
+
+    let Error::Service { source, .. } = err else {
+        panic!("unexpected error: {:?}", err);
+    };
+    let exhausted = source.downcast::<RateLimitExhausted>().unwrap();
+    assert_eq!(exhausted.resource, RateLimitResource::Core);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn should_keep_the_reserve_for_other_clients() {
+    let mock_server = setup_api(3).await;
+    let budget = RateLimitBudget::new();
+    let interactive = client(&mock_server, budget.clone());
+    let background = client(&mock_server, budget.reserve(0.5).max_wait(Duration::ZERO));
+
+    interactive._get("/repos/owner/repo").await.unwrap();
+    assert!(background._get("/repos/owner/repo").await.is_err());
+    interactive._get("/repos/owner/repo").await.unwrap();
+
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+}
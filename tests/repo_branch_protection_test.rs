@@ -0,0 +1,252 @@
+/// Tests API calls related to the branches and their protection.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::repos::branch_protection::{
+    BranchProtection, RequiredPullRequestReviewsUpdate, RequiredStatusChecksUpdate, StatusCheck,
+};
+use octocrab::models::AppId;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "example";
+
+async fn setup_api(mock: Mock) -> MockServer {
+    let mock_server = MockServer::start().await;
+    mock.expect(1).mount(&mock_server).await;
+    setup_error_handler(&mock_server, "request did not match the expected mock").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn branch_protection() -> BranchProtection {
+    serde_json::from_str(include_str!("resources/branch_protection.json")).unwrap()
+}
+
+fn branch(name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "name": name,
+        "commit": {
+            "sha": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d",
+            "url": "https://api.github.com/repos/octocat/example/commits/7fd1a60b01f91b314f59955a4e4d4e80d8edf11d",
+        },
+        "protected": true,
+        "protection": {
+            "enabled": true,
+            "required_status_checks": {
+                "enforcement_level": "non_admins",
+                "strict": true,
+                "contexts": ["ci/test"],
+                "checks": [{ "context": "ci/test", "app_id": 15368 }],
+            },
+        },
+        "protection_url": format!("https://api.github.com/repos/octocat/example/branches/{name}/protection"),
+    })
+}
+
+#[tokio::test]
+async fn should_get_branch() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/branches/main")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(branch("main"))),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let branch = client.repos(OWNER, REPO).get_branch("main").await.unwrap();
+
+    let checks = branch.protection.unwrap().required_status_checks.unwrap();
+    assert_eq!(
+        checks.checks,
+        vec![StatusCheck::new("ci/test", Some(AppId(15368)))]
+    );
+}
+
+#[tokio::test]
+async fn should_list_branches_with_protection_summary() {
+    let mut branch = branch("main");
+    // Listed branches only embed the enforcement level and the contexts.
+    branch["protection"]["required_status_checks"] = serde_json::json!({
+        "enforcement_level": "non_admins",
+        "contexts": ["ci/test"],
+    });
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/branches")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![branch])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .repos(OWNER, REPO)
+        .list_branches()
+        .send()
+        .await
+        .unwrap();
+
+    let checks = page.items[0]
+        .protection
+        .clone()
+        .unwrap()
+        .required_status_checks
+        .unwrap();
+    assert_eq!(checks.strict, None);
+    assert_eq!(checks.contexts, vec!["ci/test".to_owned()]);
+    assert!(checks.checks.is_empty());
+}
+
+#[tokio::test]
+async fn should_rename_branch() {
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/branches/master/rename"
+            )))
+            .and(body_json(serde_json::json!({ "new_name": "main" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(branch("main"))),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let branch = client
+        .repos(OWNER, REPO)
+        .rename_branch("master", "main")
+        .await
+        .unwrap();
+
+    assert_eq!(branch.name, "main");
+}
+
+#[tokio::test]
+async fn should_get_branch_protection() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/branches/main/protection"
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(branch_protection())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let protection = client
+        .repos(OWNER, REPO)
+        .branch_protection("main")
+        .get()
+        .await
+        .unwrap();
+
+    assert!(protection.enforce_admins.unwrap().enabled);
+    let reviews = protection.required_pull_request_reviews.unwrap();
+    assert_eq!(reviews.required_approving_review_count, Some(2));
+    let restrictions = reviews.dismissal_restrictions.unwrap();
+    assert_eq!(restrictions.teams[0].slug, "justice-league");
+}
+
+#[tokio::test]
+async fn should_update_branch_protection() {
+    let mock_server = setup_api(
+        Mock::given(method("PUT"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/branches/main/protection"
+            )))
+            .and(body_json(serde_json::json!({
+                "required_status_checks": {
+                    "strict": true,
+                    "checks": [{ "context": "ci/test", "app_id": null }],
+                },
+                "enforce_admins": true,
+                "required_pull_request_reviews": {
+                    "required_approving_review_count": 1,
+                },
+                "restrictions": null,
+                "required_linear_history": true,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(branch_protection())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .branch_protection("main")
+        .update()
+        .required_status_checks(RequiredStatusChecksUpdate {
+            strict: true,
+            checks: vec![StatusCheck::new("ci/test", None)],
+        })
+        .enforce_admins(true)
+        .required_pull_request_reviews(RequiredPullRequestReviewsUpdate {
+            required_approving_review_count: Some(1),
+            ..Default::default()
+        })
+        .required_linear_history(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_disable_admin_enforcement() {
+    let mock_server = setup_api(
+        Mock::given(method("DELETE"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/branches/main/protection/enforce_admins"
+            )))
+            .respond_with(ResponseTemplate::new(204)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .branch_protection("main")
+        .disable_admin_enforcement()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_remove_team_restrictions() {
+    let teams = branch_protection().restrictions.unwrap().teams;
+    let mock_server = setup_api(
+        Mock::given(method("DELETE"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/branches/main/protection/restrictions/teams"
+            )))
+            .and(body_json(
+                serde_json::json!({ "teams": ["justice-league"] }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&teams)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let remaining = client
+        .repos(OWNER, REPO)
+        .branch_protection("main")
+        .remove_team_restrictions(vec!["justice-league".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(remaining, teams);
+}
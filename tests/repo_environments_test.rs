@@ -0,0 +1,213 @@
+/// Tests API calls related to the environments of a repository.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::repos::environments::{
+    DeploymentBranchPolicySettings, Environment, EnvironmentReviewer, RequiredReviewer,
+};
+use octocrab::models::repos::secrets::{CreateRepositorySecret, CreateRepositorySecretResponse};
+use octocrab::models::{EnvironmentId, TeamId, UserId};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "example";
+
+async fn setup_api(mock: Mock) -> MockServer {
+    let mock_server = MockServer::start().await;
+    mock.expect(1).mount(&mock_server).await;
+    setup_error_handler(&mock_server, "request did not match the expected mock").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn environment() -> Environment {
+    serde_json::from_str(include_str!("resources/environment.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_get_environment() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/environments/staging")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(environment())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let environment = client
+        .repos(OWNER, REPO)
+        .environments()
+        .get("staging")
+        .await
+        .unwrap();
+
+    assert_eq!(environment.id, EnvironmentId(161088068));
+    let rules = environment.protection_rules.unwrap();
+    assert_eq!(rules[0].wait_timer, Some(30));
+    let reviewers = rules[1].reviewers.as_ref().unwrap();
+    assert!(matches!(reviewers[0], EnvironmentReviewer::User(ref user) if user.login == "octocat"));
+    assert!(
+        matches!(reviewers[1], EnvironmentReviewer::Team(ref team) if team.slug == "justice-league")
+    );
+}
+
+#[tokio::test]
+async fn should_list_environments() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/environments")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "environments": [environment()],
+            }))),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .repos(OWNER, REPO)
+        .environments()
+        .list()
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.items, vec![environment()]);
+    assert_eq!(page.total_count, Some(1));
+}
+
+#[tokio::test]
+async fn should_create_or_update_environment() {
+    let mock_server = setup_api(
+        Mock::given(method("PUT"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/environments/staging")))
+            .and(body_json(serde_json::json!({
+                "wait_timer": 30,
+                "reviewers": [
+                    { "type": "User", "id": 1 },
+                    { "type": "Team", "id": 1 },
+                ],
+                "deployment_branch_policy": {
+                    "protected_branches": false,
+                    "custom_branch_policies": true,
+                },
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(environment())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let environment = client
+        .repos(OWNER, REPO)
+        .environments()
+        .create_or_update("staging")
+        .wait_timer(30u32)
+        .reviewers(vec![
+            RequiredReviewer::User { id: UserId(1) },
+            RequiredReviewer::Team { id: TeamId(1) },
+        ])
+        .deployment_branch_policy(DeploymentBranchPolicySettings {
+            protected_branches: false,
+            custom_branch_policies: true,
+        })
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(environment.name, "staging");
+}
+
+#[tokio::test]
+async fn should_encode_environment_name() {
+    let mock_server = setup_api(
+        Mock::given(method("DELETE"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/environments/pre%20production"
+            )))
+            .respond_with(ResponseTemplate::new(204)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .environments()
+        .delete("pre production")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_create_environment_secret() {
+    let mock_server = setup_api(
+        Mock::given(method("PUT"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/environments/production/secrets/GH_TOKEN"
+            )))
+            .and(body_json(serde_json::json!({
+                "key_id": "123456",
+                "encrypted_value": "some-b64-string",
+            })))
+            .respond_with(ResponseTemplate::new(201)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .environments()
+        .secrets("production")
+        .create_or_update_secret(
+            "GH_TOKEN",
+            &CreateRepositorySecret {
+                key_id: "123456",
+                encrypted_value: "some-b64-string",
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result, CreateRepositorySecretResponse::Created);
+}
+
+#[tokio::test]
+async fn should_create_environment_variable() {
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/environments/production/variables"
+            )))
+            .and(body_json(serde_json::json!({
+                "name": "EMAIL",
+                "value": "octocat@github.com",
+            })))
+            .respond_with(ResponseTemplate::new(201)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .environments()
+        .variables("production")
+        .create("EMAIL", "octocat@github.com")
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
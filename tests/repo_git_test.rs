@@ -0,0 +1,325 @@
+/// Tests API calls related to the Git database of a repository.
+mod mock_error;
+
+use base64::Engine;
+use mock_error::setup_error_handler;
+use octocrab::models::repos::git::{FileMode, TreeEntryType, TreeEntryUpdate};
+use octocrab::params::repos::Reference;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "example";
+const BASE_URL: &str = "https://api.github.com/repos/octocat/example";
+
+async fn setup_api(mocks: Vec<Mock>) -> MockServer {
+    let mock_server = MockServer::start().await;
+    for mock in mocks {
+        mock.expect(1).mount(&mock_server).await;
+    }
+    setup_error_handler(&mock_server, "request did not match the expected mock").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn tree(sha: &str) -> serde_json::Value {
+    serde_json::json!({
+        "sha": sha,
+        "url": format!("{BASE_URL}/git/trees/{sha}"),
+        "truncated": false,
+        "tree": [
+            {
+                "path": "README.md",
+                "mode": "100644",
+                "type": "blob",
+                "sha": "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15",
+                "size": 132,
+                "url": format!("{BASE_URL}/git/blobs/3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"),
+            },
+            {
+                "path": "src",
+                "mode": "040000",
+                "type": "tree",
+                "sha": "f484d249c660418515fb01c2b9662073663c242e",
+                "url": format!("{BASE_URL}/git/trees/f484d249c660418515fb01c2b9662073663c242e"),
+            },
+        ],
+    })
+}
+
+fn git_commit(sha: &str, tree_sha: &str, parent_sha: &str) -> serde_json::Value {
+    let author = serde_json::json!({
+        "name": "Mona Octocat",
+        "email": "octocat@github.com",
+        "date": "2024-01-14T16:00:49Z",
+    });
+    serde_json::json!({
+        "sha": sha,
+        "node_id": "C_kwDOAE3WVdoAKDdmZDFhNjBiMDFmOTFiMzE0ZjU5OTU1YTRlNGQ0ZTgwZDhlZGYxMWQ",
+        "url": format!("{BASE_URL}/git/commits/{sha}"),
+        "html_url": format!("https://github.com/octocat/example/commit/{sha}"),
+        "author": author,
+        "committer": author,
+        "message": "Update the crabs",
+        "tree": { "sha": tree_sha, "url": format!("{BASE_URL}/git/trees/{tree_sha}") },
+        "parents": [{
+            "sha": parent_sha,
+            "url": format!("{BASE_URL}/git/commits/{parent_sha}"),
+            "html_url": format!("https://github.com/octocat/example/commit/{parent_sha}"),
+        }],
+        "verification": {
+            "verified": false,
+            "reason": "unsigned",
+            "signature": null,
+            "payload": null,
+        },
+    })
+}
+
+fn git_ref(sha: &str) -> serde_json::Value {
+    serde_json::json!({
+        "ref": "refs/heads/main",
+        "node_id": "MDM6UmVmcmVmcy9oZWFkcy9mZWF0dXJlQQ==",
+        "url": format!("{BASE_URL}/git/refs/heads/main"),
+        "object": {
+            "type": "commit",
+            "sha": sha,
+            "url": format!("{BASE_URL}/git/commits/{sha}"),
+        },
+    })
+}
+
+#[tokio::test]
+async fn should_get_blob() {
+    let mock_server = setup_api(vec![Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/git/blobs/3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "content": "SGVsbG8s\nIGNyYWIh\n",
+            "encoding": "base64",
+            "url": format!("{BASE_URL}/git/blobs/3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"),
+            "sha": "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15",
+            "size": 12,
+            "node_id": "Q29udGVudCBvZiBibG9i",
+        })))])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let blob = client
+        .repos(OWNER, REPO)
+        .get_blob("3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15")
+        .await
+        .unwrap();
+
+    assert_eq!(blob.decoded_content(), Some(b"Hello, crab!".to_vec()));
+}
+
+#[tokio::test]
+async fn should_create_blob() {
+    let mock_server = setup_api(vec![Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/blobs")))
+        .and(body_json(serde_json::json!({
+            "content": "AP8=",
+            "encoding": "base64",
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "url": format!("{BASE_URL}/git/blobs/3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15"),
+            "sha": "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15",
+        })))])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let blob = client
+        .repos(OWNER, REPO)
+        .create_blob([0x00, 0xff])
+        .await
+        .unwrap();
+
+    assert_eq!(blob.sha, "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15");
+}
+
+#[tokio::test]
+async fn should_create_tree() {
+    let mock_server = setup_api(vec![Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/trees")))
+        .and(body_json(serde_json::json!({
+            "base_tree": "9fb037999f264ba9a7fc6274d15fa3ae2ab98312",
+            "tree": [
+                { "path": "README.md", "mode": "100644", "type": "blob", "content": "# Crabs\n" },
+                {
+                    "path": "run.sh",
+                    "mode": "100755",
+                    "type": "blob",
+                    "sha": "44b4fc6d56897b048c772eb4087f854f46256132",
+                },
+                { "path": "obsolete.txt", "mode": "100644", "type": "blob", "sha": null },
+            ],
+        })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(tree("cd8274d15fa3ae2ab983129fb037999f264ba9a7")),
+        )])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let tree = client
+        .repos(OWNER, REPO)
+        .create_tree(vec![
+            TreeEntryUpdate::content("README.md", FileMode::File, "# Crabs\n"),
+            TreeEntryUpdate::object(
+                "run.sh",
+                FileMode::Executable,
+                TreeEntryType::Blob,
+                "44b4fc6d56897b048c772eb4087f854f46256132",
+            ),
+            TreeEntryUpdate::delete("obsolete.txt"),
+        ])
+        .base_tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(tree.sha, "cd8274d15fa3ae2ab983129fb037999f264ba9a7");
+}
+
+#[tokio::test]
+async fn should_get_tree_recursively() {
+    let mock_server = setup_api(vec![Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/git/trees/9fb037999f264ba9a7fc6274d15fa3ae2ab98312"
+        )))
+        .and(query_param("recursive", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312")),
+        )])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let tree = client
+        .repos(OWNER, REPO)
+        .get_tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312")
+        .recursive(true)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(tree.tree[0].mode, FileMode::File);
+    assert_eq!(tree.tree[1].mode, FileMode::Subdirectory);
+    assert_eq!(tree.tree[1].kind, TreeEntryType::Tree);
+}
+
+#[tokio::test]
+async fn should_force_update_ref() {
+    let mock_server = setup_api(vec![Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/git/refs/heads/main")))
+        .and(body_json(serde_json::json!({
+            "sha": "aa218f56b14c9653891f9e74264a383fa43fefbd",
+            "force": true,
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(git_ref("aa218f56b14c9653891f9e74264a383fa43fefbd")),
+        )])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .update_ref(
+            &Reference::Branch("main".to_string()),
+            "aa218f56b14c9653891f9e74264a383fa43fefbd",
+            true,
+        )
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_commit_changes_onto_branch() {
+    let head = "7638417db6d59f3c431d3e1f261cc637155684cd";
+    let base_tree = "9fb037999f264ba9a7fc6274d15fa3ae2ab98312";
+    let blob = "44b4fc6d56897b048c772eb4087f854f46256132";
+    let new_tree = "cd8274d15fa3ae2ab983129fb037999f264ba9a7";
+    let new_commit = "aa218f56b14c9653891f9e74264a383fa43fefbd";
+    let binary = [0x89, b'P', b'N', b'G', 0xff];
+
+    let mock_server = setup_api(vec![
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/git/ref/heads/main")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(git_ref(head))),
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/git/commits/{head}")))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(git_commit(
+                    head,
+                    base_tree,
+                    "c3d0be41ecbe669545ee3e94d31ed9a4bc91ee3c",
+                )),
+            ),
+        Mock::given(method("POST"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/git/blobs")))
+            .and(body_json(serde_json::json!({
+                "content": base64::prelude::BASE64_STANDARD.encode(binary),
+                "encoding": "base64",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "sha": blob,
+                "url": format!("{BASE_URL}/git/blobs/{blob}"),
+            }))),
+        Mock::given(method("POST"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/git/trees")))
+            .and(body_json(serde_json::json!({
+                "base_tree": base_tree,
+                "tree": [
+                    { "path": "crabs/ferris.txt", "mode": "100644", "type": "blob", "content": "Ferris\n" },
+                    { "path": "crabs/ferris.png", "mode": "100644", "type": "blob", "sha": blob },
+                    { "path": "crabs/crab.txt", "mode": "100644", "type": "blob", "sha": null },
+                ],
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(tree(new_tree))),
+        Mock::given(method("POST"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/git/commits")))
+            .and(body_json(serde_json::json!({
+                "message": "Update the crabs",
+                "tree": new_tree,
+                "parents": [head],
+            })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(git_commit(new_commit, new_tree, head)),
+            ),
+        Mock::given(method("PATCH"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/git/refs/heads/main")))
+            .and(body_json(serde_json::json!({
+                "sha": new_commit,
+                "force": false,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(git_ref(new_commit))),
+    ])
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let commit = client
+        .repos(OWNER, REPO)
+        .commit_changes("main", "Update the crabs")
+        .write("crabs/ferris.txt", "Ferris\n")
+        .write("crabs/ferris.png", binary)
+        .delete("crabs/crab.txt")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(commit.sha, new_commit);
+}
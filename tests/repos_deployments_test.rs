@@ -0,0 +1,216 @@
+/// Tests API calls related to the deployments of a repository.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::repos::deployments::{
+    CreateDeploymentResponse, Deployment, DeploymentState, DeploymentStatus,
+    ProtectionRuleReviewState,
+};
+use octocrab::models::{DeploymentId, RunId};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "example";
+
+async fn setup_api(mock: Mock) -> MockServer {
+    let mock_server = MockServer::start().await;
+    mock.expect(1).mount(&mock_server).await;
+    setup_error_handler(&mock_server, "request did not match the expected mock").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn deployment() -> Deployment {
+    serde_json::from_str(include_str!("resources/deployment.json")).unwrap()
+}
+
+fn deployment_status() -> DeploymentStatus {
+    serde_json::from_str(include_str!("resources/deployment_status.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_list_deployments() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/deployments")))
+            .and(query_param("environment", "production"))
+            .and(query_param("ref", "topic-branch"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![deployment()])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .list()
+        .environment("production")
+        .reference("topic-branch")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.items, vec![deployment()]);
+}
+
+#[tokio::test]
+async fn should_create_deployment() {
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/deployments")))
+            .and(body_json(serde_json::json!({
+                "ref": "topic-branch",
+                "auto_merge": false,
+                "required_contexts": [],
+                "payload": { "deploy": "migrate" },
+                "environment": "production",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(deployment())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let created = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .create("topic-branch")
+        .auto_merge(false)
+        .required_contexts(Vec::new())
+        .payload(serde_json::json!({ "deploy": "migrate" }))
+        .environment("production")
+        .send()
+        .await
+        .unwrap();
+
+    match created {
+        CreateDeploymentResponse::Created(deployment) => {
+            assert_eq!(deployment.id, DeploymentId(1));
+            assert_eq!(deployment.ref_field, "topic-branch");
+        }
+        response => panic!("expected a created deployment, got {:#?}", response),
+    }
+}
+
+#[tokio::test]
+async fn should_report_auto_merge_of_deployment_ref() {
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/deployments")))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+                "message": "Auto-merged main into topic-branch on deployment.",
+            }))),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let response = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .create("topic-branch")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response,
+        CreateDeploymentResponse::AutoMerged {
+            message: "Auto-merged main into topic-branch on deployment.".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn should_delete_deployment() {
+    let mock_server = setup_api(
+        Mock::given(method("DELETE"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/deployments/1")))
+            .respond_with(ResponseTemplate::new(204)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .delete(DeploymentId(1))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_create_deployment_status() {
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/deployments/42/statuses"
+            )))
+            .and(body_json(serde_json::json!({
+                "state": "success",
+                "log_url": "https://example.com/deployment/42/output",
+                "environment_url": "https://test-branch.lab.acme.com",
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(deployment_status())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let status = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .create_status(DeploymentId(42), DeploymentState::Success)
+        .log_url("https://example.com/deployment/42/output")
+        .environment_url("https://test-branch.lab.acme.com")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(status.state, DeploymentState::Success);
+}
+
+#[tokio::test]
+async fn should_reject_deployment_protection_rule() {
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/repos/{OWNER}/{REPO}/actions/runs/1234/deployment_protection_rule"
+            )))
+            .and(body_json(serde_json::json!({
+                "environment_name": "production",
+                "state": "rejected",
+                "comment": "Health checks failed.",
+            })))
+            .respond_with(ResponseTemplate::new(204)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .deployments()
+        .review_protection_rule(
+            RunId(1234),
+            "production",
+            ProtectionRuleReviewState::Rejected,
+        )
+        .comment("Health checks failed.")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
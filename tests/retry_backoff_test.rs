@@ -0,0 +1,96 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+use http::{Request, StatusCode};
+use octocrab::{
+    service::middleware::retry::{ExponentialBackoff, RateLimitMetrics, RetryConfig},
+    OctoBody, Octocrab,
+};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[derive(Default)]
+struct CountingMetrics {
+    backoffs: AtomicUsize,
+}
+
+impl RateLimitMetrics for CountingMetrics {
+    fn retry_after_error(&self, _: &Request<OctoBody>, _: StatusCode, _: usize) {}
+    fn rate_limited(&self, _: &Request<OctoBody>, _: StatusCode, _: usize, _: u64) {}
+    fn backoff(
+        &self,
+        _: &Request<OctoBody>,
+        status_code: Option<StatusCode>,
+        _: usize,
+        delay: Duration,
+    ) {
+        assert_eq!(status_code, Some(StatusCode::BAD_GATEWAY));
+        assert!(delay <= Duration::from_millis(20));
+        self.backoffs.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn should_retry_server_errors_with_backoff() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .mount(&mock_server)
+        .await;
+
+    let metrics = Arc::new(CountingMetrics::default());
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .add_retry_config(RetryConfig::ExponentialBackoff(
+            ExponentialBackoff::new()
+                .metrics(metrics.clone())
+                .base_delay(Duration::from_millis(10))
+                .max_delay(Duration::from_millis(20)),
+        ))
+        .build()
+        .unwrap();
+
+    let response = client._get("/repos/owner/repo").await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(metrics.backoffs.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn should_give_up_after_max_retries() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .add_retry_config(RetryConfig::ExponentialBackoff(
+            ExponentialBackoff::new()
+                .max_retries(2)
+                .base_delay(Duration::from_millis(1)),
+        ))
+        .build()
+        .unwrap();
+
+    let response = client._get("/repos/owner/repo").await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+}
@@ -0,0 +1,122 @@
+use octocrab::{service::middleware::retry::RetryConfig, Octocrab};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/1/comments"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo/pulls/1"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/1"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .add_retry_config(RetryConfig::Simple(2))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_not_retry_post_by_default() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues("owner", "repo").create("title").send().await;
+
+    assert!(result.is_err());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn should_retry_post_marked_retryable() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues("owner", "repo")
+        .create("title")
+        .retryable(true)
+        .send()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn should_retry_patch_marked_retryable() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls("owner", "repo")
+        .update(1)
+        .title("title")
+        .send()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+    let result = client
+        .pulls("owner", "repo")
+        .update(1)
+        .title("title")
+        .retryable(true)
+        .send()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn should_retry_get() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues("owner", "repo").get(1).await;
+
+    assert!(result.is_err());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn should_retry_comment_created_as_retryable() {
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues("owner", "repo")
+        .create_comment(1, "Beep Boop")
+        .await;
+    assert!(result.is_err());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+    let result = client
+        .issues("owner", "repo")
+        .create_comment_retryable(1, "Beep Boop")
+        .await;
+    assert!(result.is_err());
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 4);
+}
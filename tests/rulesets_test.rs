@@ -0,0 +1,374 @@
+/// Tests API calls related to the rulesets of repositories and organizations.
+mod mock_error;
+
+use mock_error::setup_error_handler;
+use octocrab::models::rulesets::{
+    AllowedMergeMethod, BranchRule, BypassActor, BypassActorType, BypassMode, CurrentUserCanBypass,
+    MergeQueueGroupingStrategy, RefNameCondition, RepositoryNameCondition,
+    RequiredStatusChecksParameters, Rule, RuleSuite, RuleSuiteResult, Ruleset, RulesetConditions,
+    RulesetEnforcement, RulesetSourceType, RulesetTarget, StatusCheckConfiguration,
+    UpdateParameters,
+};
+use octocrab::models::{RuleSuiteId, RulesetId};
+use octocrab::params::rulesets::TimePeriod;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "octocat";
+const REPO: &str = "example";
+
+async fn setup_api(mock: Mock) -> MockServer {
+    let mock_server = MockServer::start().await;
+    mock.expect(1).mount(&mock_server).await;
+    setup_error_handler(&mock_server, "request did not match the expected mock").await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn ruleset() -> Ruleset {
+    serde_json::from_str(include_str!("resources/ruleset.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_get_repo_ruleset() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/rulesets/42")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ruleset())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let ruleset = client
+        .repos(OWNER, REPO)
+        .rulesets()
+        .get(RulesetId(42))
+        .await
+        .unwrap();
+
+    assert_eq!(ruleset.enforcement, RulesetEnforcement::Active);
+    assert_eq!(ruleset.source_type, Some(RulesetSourceType::Repository));
+    let rules = ruleset.rules.unwrap();
+    assert_eq!(rules[0], Rule::Deletion);
+    assert_eq!(
+        rules[2],
+        Rule::Update(Some(UpdateParameters {
+            update_allows_fetch_and_merge: true
+        }))
+    );
+    match &rules[3] {
+        Rule::PullRequest(parameters) => {
+            assert_eq!(parameters.required_approving_review_count, 2);
+            assert_eq!(
+                parameters.allowed_merge_methods,
+                Some(vec![AllowedMergeMethod::Squash, AllowedMergeMethod::Rebase])
+            );
+        }
+        rule => panic!("expected a pull request rule, got {:#?}", rule),
+    }
+    match &rules[5] {
+        Rule::MergeQueue(parameters) => {
+            assert_eq!(
+                parameters.grouping_strategy,
+                MergeQueueGroupingStrategy::AllGreen
+            );
+        }
+        rule => panic!("expected a merge queue rule, got {:#?}", rule),
+    }
+}
+
+#[tokio::test]
+async fn should_list_org_rulesets() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path("/orgs/org/rulesets"))
+            .and(query_param("targets", "branch,push"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![ruleset()])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .orgs("org")
+        .rulesets()
+        .list()
+        .targets(vec![RulesetTarget::Branch, RulesetTarget::Push])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.items, vec![ruleset()]);
+}
+
+#[tokio::test]
+async fn should_create_org_ruleset() {
+    let mock_server = setup_api(
+        Mock::given(method("POST"))
+            .and(path("/orgs/org/rulesets"))
+            .and(body_json(serde_json::json!({
+                "name": "protect main",
+                "enforcement": "evaluate",
+                "target": "branch",
+                "bypass_actors": [
+                    { "actor_id": 234, "actor_type": "Team", "bypass_mode": "pull_request" },
+                ],
+                "conditions": {
+                    "ref_name": { "include": ["~DEFAULT_BRANCH"], "exclude": [] },
+                    "repository_name": { "include": ["~ALL"], "exclude": ["sandbox"] },
+                },
+                "rules": [
+                    { "type": "required_linear_history" },
+                    {
+                        "type": "required_status_checks",
+                        "parameters": {
+                            "required_status_checks": [
+                                { "context": "ci/test", "integration_id": 15368 },
+                            ],
+                            "strict_required_status_checks_policy": true,
+                        },
+                    },
+                ],
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(ruleset())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs("org")
+        .rulesets()
+        .create("protect main", RulesetEnforcement::Evaluate)
+        .target(RulesetTarget::Branch)
+        .bypass_actors(vec![
+            BypassActor::new(Some(234), BypassActorType::Team).bypass_mode(BypassMode::PullRequest)
+        ])
+        .conditions(
+            RulesetConditions::default()
+                .ref_name(RefNameCondition {
+                    include: vec!["~DEFAULT_BRANCH".to_string()],
+                    exclude: vec![],
+                })
+                .repository_name(RepositoryNameCondition {
+                    include: vec!["~ALL".to_string()],
+                    exclude: vec!["sandbox".to_string()],
+                    protected: None,
+                }),
+        )
+        .rules(vec![
+            Rule::RequiredLinearHistory,
+            Rule::RequiredStatusChecks(RequiredStatusChecksParameters {
+                do_not_enforce_on_create: None,
+                required_status_checks: vec![
+                    StatusCheckConfiguration::new("ci/test").integration_id(15368u64)
+                ],
+                strict_required_status_checks_policy: true,
+            }),
+        ])
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_update_repo_ruleset() {
+    let mock_server = setup_api(
+        Mock::given(method("PUT"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/rulesets/42")))
+            .and(body_json(serde_json::json!({ "enforcement": "disabled" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ruleset())),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .rulesets()
+        .update(RulesetId(42))
+        .enforcement(RulesetEnforcement::Disabled)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_delete_repo_ruleset() {
+    let mock_server = setup_api(
+        Mock::given(method("DELETE"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/rulesets/42")))
+            .respond_with(ResponseTemplate::new(204)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .rulesets()
+        .delete(RulesetId(42))
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_list_org_rule_suites() {
+    let rule_suite: RuleSuite = serde_json::from_value(serde_json::json!({
+        "id": 21,
+        "actor_id": 12,
+        "actor_name": "octocat",
+        "before_sha": "893f768e172fb1bc9c5d6f3dd48557e45f14e01d",
+        "after_sha": "dedd88641a362b6b4ea872da4847d6131a164d01",
+        "ref": "refs/heads/main",
+        "repository_id": 404,
+        "repository_name": "octo-repo",
+        "pushed_at": "2023-07-06T08:43:03Z",
+        "result": "bypass",
+    }))
+    .unwrap();
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path("/orgs/org/rulesets/rule-suites"))
+            .and(query_param("repository_name", "octo-repo"))
+            .and(query_param("time_period", "week"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![rule_suite.clone()])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .orgs("org")
+        .rulesets()
+        .list_rule_suites()
+        .repository_name("octo-repo")
+        .time_period(TimePeriod::Week)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.items[0].id, RuleSuiteId(21));
+    assert_eq!(page.items[0].result, RuleSuiteResult::Bypass);
+}
+
+#[tokio::test]
+async fn should_list_branch_rules() {
+    let mock_server = setup_api(
+        Mock::given(method("GET"))
+            .and(path(format!("/repos/{OWNER}/{REPO}/rules/branches/main")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "type": "commit_message_pattern",
+                    "ruleset_source_type": "Repository",
+                    "ruleset_source": "octocat/example",
+                    "ruleset_id": 42,
+                    "parameters": {
+                        "operator": "starts_with",
+                        "pattern": "issue",
+                    },
+                },
+                {
+                    "type": "deletion",
+                    "ruleset_source_type": "Organization",
+                    "ruleset_source": "octocat",
+                    "ruleset_id": 43,
+                },
+            ]))),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .repos(OWNER, REPO)
+        .branch_rules("main")
+        .send()
+        .await
+        .unwrap();
+
+    let rules: Vec<BranchRule> = page.items;
+    assert!(
+        matches!(rules[0].rule, Rule::CommitMessagePattern(ref parameters) if parameters.pattern == "issue")
+    );
+    assert_eq!(rules[1].rule, Rule::Deletion);
+    assert_eq!(
+        rules[1].ruleset_source_type,
+        RulesetSourceType::Organization
+    );
+    assert_eq!(rules[1].ruleset_id, RulesetId(43));
+}
+
+#[test]
+fn should_keep_unknown_rules_and_actor_types() {
+    let ruleset: Ruleset = serde_json::from_value(serde_json::json!({
+        "id": 42,
+        "name": "copilot",
+        "target": "branch",
+        "source": "octocat/example",
+        "enforcement": "active",
+        "bypass_actors": [
+            { "actor_id": 1, "actor_type": "EnterpriseOwner", "bypass_mode": "always" },
+            { "actor_id": 2, "actor_type": "SomethingNew", "bypass_mode": "always" },
+            { "actor_id": 3, "actor_type": "Team", "bypass_mode": "exempt" },
+            { "actor_id": 4, "actor_type": "Team", "bypass_mode": "something_new" },
+        ],
+        "current_user_can_bypass": "exempt",
+        "rules": [
+            { "type": "deletion" },
+            {
+                "type": "copilot_code_review",
+                "parameters": { "review_on_push": true },
+            },
+        ],
+    }))
+    .unwrap();
+
+    assert_eq!(
+        ruleset.current_user_can_bypass,
+        Some(CurrentUserCanBypass::Exempt)
+    );
+    let actors = ruleset.bypass_actors.unwrap();
+    assert_eq!(actors[0].actor_type, BypassActorType::EnterpriseOwner);
+    assert_eq!(
+        actors[1].actor_type,
+        BypassActorType::Other("SomethingNew".to_owned())
+    );
+    assert_eq!(
+        serde_json::to_value(&actors[1]).unwrap(),
+        serde_json::json!({ "actor_id": 2, "actor_type": "SomethingNew", "bypass_mode": "always" })
+    );
+    assert_eq!(actors[2].bypass_mode, Some(BypassMode::Exempt));
+    assert_eq!(actors[3].bypass_mode, Some(BypassMode::Unknown));
+    let rules = ruleset.rules.unwrap();
+    assert_eq!(rules[0], Rule::Deletion);
+    assert_eq!(
+        rules[1],
+        Rule::Other {
+            rule_type: "copilot_code_review".to_owned(),
+            parameters: serde_json::json!({ "review_on_push": true }),
+        }
+    );
+    assert_eq!(
+        serde_json::to_value(&rules[1]).unwrap(),
+        serde_json::json!({
+            "type": "copilot_code_review",
+            "parameters": { "review_on_push": true },
+        })
+    );
+}
@@ -0,0 +1,132 @@
+use std::time::{Duration, Instant};
+
+use octocrab::{
+    service::middleware::{retry::RetryConfig, throttle::ThrottleConfig},
+    Octocrab,
+};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn client(mock_server: &MockServer, throttle: ThrottleConfig) -> Octocrab {
+    client_with_retries(mock_server, RetryConfig::None, throttle)
+}
+
+fn client_with_retries(
+    mock_server: &MockServer,
+    retry: RetryConfig,
+    throttle: ThrottleConfig,
+) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .add_retry_config(retry)
+        .throttle(throttle)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_space_out_mutations() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/labels"))
+        .respond_with(ResponseTemplate::new(201).set_body_string("{}"))
+        .mount(&mock_server)
+        .await;
+    let client = client(
+        &mock_server,
+        ThrottleConfig::new().mutation_interval(Duration::from_millis(200)),
+    );
+
+    let start = Instant::now();
+    let requests = (0..3).map(|_| client._post("/repos/owner/repo/labels", None::<&()>));
+    for response in futures::future::join_all(requests).await {
+        response.unwrap();
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn should_space_out_retried_mutations() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/owner/repo/subscription"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/owner/repo/subscription"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .mount(&mock_server)
+        .await;
+    let client = client_with_retries(
+        &mock_server,
+        RetryConfig::Simple(1),
+        ThrottleConfig::new().mutation_interval(Duration::from_millis(300)),
+    );
+
+    let start = Instant::now();
+    let response = client
+        ._put("/repos/owner/repo/subscription", None::<&()>)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn should_cap_concurrent_requests() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("{}")
+                .set_delay(Duration::from_millis(200)),
+        )
+        .mount(&mock_server)
+        .await;
+    let client = client(
+        &mock_server,
+        ThrottleConfig::new().max_concurrent_requests(1),
+    );
+
+    let start = Instant::now();
+    let requests = (0..3).map(|_| client._get("/repos/owner/repo"));
+    for response in futures::future::join_all(requests).await {
+        response.unwrap();
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(600));
+}
+
+#[tokio::test]
+async fn should_pause_after_secondary_rate_limit() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(403).append_header("retry-after", "1"))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .mount(&mock_server)
+        .await;
+    let client = client(&mock_server, ThrottleConfig::new());
+
+    let response = client._get("/repos/owner/repo").await.unwrap();
+    assert_eq!(response.status(), 403);
+
+    let start = Instant::now();
+    let response = client._get("/repos/owner/repo").await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert!(start.elapsed() >= Duration::from_millis(900));
+}
@@ -0,0 +1,136 @@
+use octocrab::{Octocrab, Page};
+use wiremock::{
+    matchers::{header, header_exists, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const AUTHORIZATION: &str = "Bearer ghe_token";
+
+/// Serves the first page of an enterprise listing, linking to the second page
+/// on `next_server` with an absolute url.
+async fn setup_first_page(server: &MockServer, next_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/repos"))
+        .and(header("authorization", AUTHORIZATION))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{ "id": 1 }]))
+                .append_header(
+                    "link",
+                    format!(
+                        r#"<{}/orgs/org/repos?page=2>; rel="next""#,
+                        next_server.uri()
+                    )
+                    .as_str(),
+                ),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn setup_second_page(server: &MockServer, authorized: bool) {
+    let mock = Mock::given(method("GET"))
+        .and(path("/orgs/org/repos"))
+        .and(query_param("page", "2"));
+    let mock = if authorized {
+        mock.and(header("authorization", AUTHORIZATION))
+    } else {
+        mock
+    };
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "id": 2 }])))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn fetch_both_pages(client: &Octocrab) -> Vec<serde_json::Value> {
+    let first: Page<serde_json::Value> = client.get("/orgs/org/repos", None::<&()>).await.unwrap();
+    let second: Page<serde_json::Value> = client.get_page(&first.next).await.unwrap().unwrap();
+    first.items.into_iter().chain(second.items).collect()
+}
+
+#[tokio::test]
+async fn should_send_personal_token_to_next_page_on_base_host() {
+    let ghes = MockServer::start().await;
+    // Mounted first, so that it takes precedence over the first page.
+    setup_second_page(&ghes, true).await;
+    setup_first_page(&ghes, &ghes).await;
+
+    let client = Octocrab::builder()
+        .base_uri(ghes.uri())
+        .unwrap()
+        .personal_token("ghe_token")
+        .build()
+        .unwrap();
+
+    assert_eq!(fetch_both_pages(&client).await.len(), 2);
+}
+
+#[tokio::test]
+async fn should_send_credentials_to_next_page_on_base_host() {
+    let ghes = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/repos"))
+        .and(query_param("page", "2"))
+        .and(header_exists("authorization"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "id": 2 }])))
+        .expect(1)
+        .mount(&ghes)
+        .await;
+
+    let client = Octocrab::builder()
+        .base_uri(ghes.uri())
+        .unwrap()
+        .basic_auth("user".to_owned(), "password".to_owned())
+        .build()
+        .unwrap();
+
+    let next = Some(
+        format!("{}/orgs/org/repos?page=2", ghes.uri())
+            .parse()
+            .unwrap(),
+    );
+    let page: Page<serde_json::Value> = client.get_page(&next).await.unwrap().unwrap();
+    assert_eq!(page.items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_send_credentials_to_added_trusted_host() {
+    let ghes = MockServer::start().await;
+    let mirror = MockServer::start().await;
+    setup_first_page(&ghes, &mirror).await;
+    setup_second_page(&mirror, true).await;
+
+    let mirror_authority = mirror.uri().parse::<http::Uri>().unwrap();
+    let client = Octocrab::builder()
+        .base_uri(ghes.uri())
+        .unwrap()
+        .add_trusted_host(mirror_authority.authority().unwrap().as_str())
+        .personal_token("ghe_token")
+        .build()
+        .unwrap();
+
+    assert_eq!(fetch_both_pages(&client).await.len(), 2);
+}
+
+#[tokio::test]
+async fn should_not_send_credentials_to_untrusted_host() {
+    let ghes = MockServer::start().await;
+    let third_party = MockServer::start().await;
+    setup_first_page(&ghes, &third_party).await;
+    setup_second_page(&third_party, false).await;
+
+    let client = Octocrab::builder()
+        .base_uri(ghes.uri())
+        .unwrap()
+        .personal_token("ghe_token")
+        .build()
+        .unwrap();
+
+    assert_eq!(fetch_both_pages(&client).await.len(), 2);
+    let requests = third_party.received_requests().await.unwrap();
+    assert!(requests
+        .iter()
+        .all(|request| !request.headers.contains_key("authorization")));
+}
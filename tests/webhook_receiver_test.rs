@@ -0,0 +1,170 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use hmac::{Hmac, Mac};
+use http::{Request, StatusCode};
+use http_body_util::Full;
+use octocrab::models::webhook_events::{
+    payload::{IssuesWebhookEventPayload, PingWebhookEventPayload},
+    WebhookVerifier,
+};
+use octocrab::models::HookId;
+use octocrab::service::webhook::{WebhookDelivery, WebhookReceiver};
+use tower::ServiceExt;
+
+const SECRET: &str = "webhook secret";
+const PING: &str = include_str!("resources/ping_webhook_event.json");
+
+fn sign(body: &str) -> String {
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+    mac.update(body.as_bytes());
+    let digest: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("sha256={digest}")
+}
+
+fn delivery(event: &str, id: &str, signature: &str, body: &str) -> Request<Full<bytes::Bytes>> {
+    Request::post("/webhook")
+        .header("X-GitHub-Event", event)
+        .header("X-GitHub-Delivery", id)
+        .header("X-Hub-Signature-256", signature)
+        .body(Full::new(bytes::Bytes::from(body.to_owned())))
+        .unwrap()
+}
+
+fn ping_receiver(calls: Arc<AtomicUsize>) -> WebhookReceiver {
+    WebhookReceiver::new(WebhookVerifier::new(SECRET)).on(
+        move |delivery: WebhookDelivery<PingWebhookEventPayload>| {
+            let calls = calls.clone();
+            async move {
+                assert_eq!(delivery.payload.hook_id.unwrap(), HookId(423885699));
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        },
+    )
+}
+
+#[tokio::test]
+async fn dispatches_verified_delivery_to_typed_handler() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let receiver = ping_receiver(calls.clone());
+
+    let response = receiver
+        .oneshot(delivery("ping", "1", &sign(PING), PING))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn skips_redelivered_events() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let receiver = ping_receiver(calls.clone());
+
+    for _ in 0..2 {
+        let response = receiver
+            .clone()
+            .oneshot(delivery("ping", "same-id", &sign(PING), PING))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn rejects_invalid_signature() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let receiver = ping_receiver(calls.clone());
+
+    let response = receiver
+        .oneshot(delivery("ping", "1", &sign("something else"), PING))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn rejects_malformed_requests() {
+    let receiver = ping_receiver(Arc::new(AtomicUsize::new(0)));
+
+    let response = receiver
+        .clone()
+        .oneshot(delivery("ping", "1", &sign("{"), "{"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = receiver
+        .clone()
+        .oneshot(
+            Request::get("/webhook")
+                .body(Full::new(bytes::Bytes::new()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+    let response = receiver
+        .max_body_size(16)
+        .oneshot(delivery("ping", "1", &sign(PING), PING))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn unhandled_events_are_acknowledged() {
+    let receiver = WebhookReceiver::new(WebhookVerifier::new(SECRET))
+        .on(|_: WebhookDelivery<IssuesWebhookEventPayload>| async { Ok(()) });
+
+    let response = receiver
+        .oneshot(delivery("ping", "1", &sign(PING), PING))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn failed_handler_allows_redelivery() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let receiver = WebhookReceiver::new(WebhookVerifier::new(SECRET)).on_any(move |_| {
+        let calls = counter.clone();
+        async move {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err("temporary failure".into())
+            } else {
+                Ok(())
+            }
+        }
+    });
+
+    let response = receiver
+        .clone()
+        .oneshot(delivery("ping", "1", &sign(PING), PING))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let response = receiver
+        .oneshot(delivery("ping", "1", &sign(PING), PING))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}